@@ -1,4 +1,4 @@
-use crate::Errors;
+use crate::{Errors, DEFAULT_EPSILON};
 
 /// # Determinant
 /// The determinant is a scalar value that is a function of the entries of a square matrix. It characterizes some properties of the matrix and the linear map represented by the matrix.<br>
@@ -23,6 +23,9 @@ pub struct Determinant {
     items: Vec<f64>,
     pub size: u32,
 }
+/// Largest size expanded by cofactors, bigger determinants use LU decomposition
+const RECURSION_LIMIT: usize = 10;
+
 impl Determinant {
     pub fn new(items: Vec<f64>) -> Result<Determinant, Errors> {
         let size = (items.len() as f32).sqrt();
@@ -32,47 +35,60 @@ impl Determinant {
         let size = size as u32;
         Ok(Determinant { items, size })
     }
-    fn value_inner(&self, items: Vec<f64>) -> f64 {
+    fn value_inner(&self, rows: &[usize], columns: &[usize]) -> f64 {
+        // the minor is described by the rows and columns it keeps, so nothing but the
+        // row indices is copied along the recursion
+        let item = |row: usize, column: usize| self.items[row * self.size as usize + column];
+
         // just in case :)
-        if items.is_empty() {
+        if rows.is_empty() {
             return 0.0;
         }
 
-        if items.len() == 1 {
+        if rows.len() > RECURSION_LIMIT {
+            // too deep to expand, factorize instead
+            let items: Vec<f64> = rows
+                .iter()
+                .flat_map(|&row| columns.iter().map(move |&column| (row, column)))
+                .map(|(row, column)| item(row, column))
+                .collect();
+            return Lu::new(&items, rows.len()).determinant();
+        }
+
+        if rows.len() == 1 {
             // 1x1 determinant
-            return items[0];
+            return item(rows[0], columns[0]);
         }
-        if items.len() == 4 {
+        if rows.len() == 2 {
             // 2x2 determinant
-            return items[0] * items[3] - items[1] * items[2];
+            return item(rows[0], columns[0]) * item(rows[1], columns[1])
+                - item(rows[0], columns[1]) * item(rows[1], columns[0]);
+        }
+        if rows.len() == 3 {
+            // 3x3 determinant, rule of Sarrus
+            let a = |i: usize, j: usize| item(rows[i], columns[j]);
+            return a(0, 0) * a(1, 1) * a(2, 2)
+                + a(0, 1) * a(1, 2) * a(2, 0)
+                + a(0, 2) * a(1, 0) * a(2, 1)
+                - a(0, 2) * a(1, 1) * a(2, 0)
+                - a(0, 0) * a(1, 2) * a(2, 1)
+                - a(0, 1) * a(1, 0) * a(2, 2);
         }
 
         // we are already calculating along the first column
         let mut value = 0.0;
-        let new_size = (items.len() as f32).sqrt() as u32;
-        for i in 0..new_size {
-            let item = items[(i * new_size) as usize];
-            let minor = self.value_inner(
-                items
-                    .iter()
-                    .enumerate()
-                    .filter(|&(j, _)| {
-                        let row = j as u32 / new_size;
-                        let column = j as u32 % new_size;
-                        if i == row || column == 0 {
-                            return false;
-                        }
-                        true
-                    })
-                    .map(|(_, x)| *x)
-                    .collect(),
-            );
-            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
-            value += minor * item * sign;
+        let mut minor_rows = Vec::with_capacity(rows.len() - 1);
+        for i in 0..rows.len() {
+            minor_rows.clear();
+            minor_rows.extend(rows[..i].iter().chain(&rows[i + 1..]));
+            let minor = self.value_inner(&minor_rows, &columns[1..]);
+            // the same (-1)^(i + j) as the public cofactors, with i and j made 1-based
+            value += minor * item(rows[i], columns[0]) * cofactor_sign(i as u32 + 1, 1);
         }
         value
     }
-    /// Calculate the value of determinant
+    /// Calculate the value of determinant<br>
+    /// Up to 10x10 it expands along the first column, bigger determinants use LU decomposition
     /// ```
     /// use math_matrix::Determinant;
     /// let det = Determinant::new(vec![9.0, 8.0, 4.0, 8.0, 3.0, 2.0, 4.0, 3.0, 2.0]).unwrap();
@@ -80,7 +96,8 @@ impl Determinant {
     /// assert_eq!(det.value(), -16.0);
     /// ```
     pub fn value(&self) -> f64 {
-        self.value_inner(self.items.clone())
+        let indices: Vec<usize> = (0..self.size as usize).collect();
+        self.value_inner(&indices, &indices)
     }
     /// Get the cofactor of an item
     /// ```
@@ -95,24 +112,269 @@ impl Determinant {
         if i == 0 || i > self.size || j == 0 || j > self.size {
             return Err(Errors::IndexOutOfRange);
         }
-        let minor = self.value_inner(
-            self.items
-                .iter()
-                .enumerate()
-                .filter(|&(x, _)| {
-                    let row = x as u32 / self.size;
-                    let column = x as u32 % self.size;
-                    if (i - 1) == row || (j - 1) == column {
-                        return false;
+        let rows: Vec<usize> = (0..self.size as usize)
+            .filter(|&row| row != i as usize - 1)
+            .collect();
+        let columns: Vec<usize> = (0..self.size as usize)
+            .filter(|&column| column != j as usize - 1)
+            .collect();
+        let minor = self.value_inner(&rows, &columns);
+        Ok(minor * cofactor_sign(i, j))
+    }
+    /// Calculate the exact value of a determinant with integer items<br>
+    /// Uses the Bareiss algorithm in `i128`, returns [`None`] if an item is not an integer or the calculation overflows
+    /// ```
+    /// use math_matrix::Determinant;
+    /// let det = Determinant::new(vec![2.0, -3.0, 1.0, 2.0, 0.0, -1.0, 1.0, 4.0, 5.0]).unwrap();
+    /// let fractional_det = Determinant::new(vec![0.5, 1.0, 2.0, 3.0]).unwrap();
+    ///
+    /// assert_eq!(det.value_exact_i128(), Some(49));
+    /// assert_eq!(fractional_det.value_exact_i128(), None);
+    /// ```
+    pub fn value_exact_i128(&self) -> Option<i128> {
+        let n = self.size as usize;
+        if n == 0 {
+            return Some(0);
+        }
+        let mut items = Vec::with_capacity(self.items.len());
+        for &item in self.items.iter() {
+            if item.fract() != 0.0 || item.abs() >= i64::MAX as f64 {
+                return None;
+            }
+            items.push(item as i128);
+        }
+
+        bareiss(items, n)
+    }
+    /// Get the sign of the determinant, `-1.0`, `0.0` or `1.0`<br>
+    /// Only the signs of the LU pivots and the row swaps are tracked, so the magnitude can't overflow<br>
+    /// Returns [`Result`], [`Ok`] if the determinant has items, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Determinant;
+    /// let det = Determinant::new(vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+    /// let zero_det = Determinant::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]).unwrap();
+    ///
+    /// assert_eq!(det.sign().unwrap(), -1.0);
+    /// assert_eq!(zero_det.sign().unwrap(), 0.0);
+    /// ```
+    pub fn sign(&self) -> Result<f64, Errors> {
+        if self.size == 0 {
+            return Err(Errors::InappropriateNumberOfItems);
+        }
+        let size = self.size as usize;
+        let lu = Lu::new(&self.items, size);
+        // relative to the largest item, so uniformly tiny determinants keep their sign
+        if lu.is_singular(DEFAULT_EPSILON) {
+            return Ok(0.0);
+        }
+        let negative_pivots = (0..size).filter(|&k| lu.items[k * size + k] < 0.0).count();
+        if (negative_pivots + lu.swaps).is_multiple_of(2) {
+            Ok(1.0)
+        } else {
+            Ok(-1.0)
+        }
+    }
+}
+
+/// # Cofactor sign
+/// Get `(-1)^(i + j)`, the sign of the cofactor at row `i` and column `j`<br>
+/// Like everywhere else, `i` and `j` start from 1
+/// ```
+/// use math_matrix::cofactor_sign;
+///
+/// assert_eq!(cofactor_sign(1, 1), 1.0);
+/// assert_eq!(cofactor_sign(1, 2), -1.0);
+/// assert_eq!(cofactor_sign(3, 1), 1.0);
+/// ```
+pub fn cofactor_sign(i: u32, j: u32) -> f64 {
+    if (i + j).is_multiple_of(2) {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// Determinant of integer items in row by row order with the Bareiss algorithm, [`None`] if it overflows
+fn bareiss(mut items: Vec<i128>, n: usize) -> Option<i128> {
+    if n == 0 {
+        return Some(0);
+    }
+    let mut sign = 1;
+    let mut previous_pivot = 1;
+    for k in 0..n - 1 {
+        if items[k * n + k] == 0 {
+            // find a row below with a non zero pivot
+            match (k + 1..n).find(|&r| items[r * n + k] != 0) {
+                Some(r) => {
+                    for c in 0..n {
+                        items.swap(k * n + c, r * n + c);
                     }
-                    true
+                    sign = -sign;
+                }
+                None => return Some(0),
+            }
+        }
+        let pivot = items[k * n + k];
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let value = items[i * n + j]
+                    .checked_mul(pivot)?
+                    .checked_sub(items[i * n + k].checked_mul(items[k * n + j])?)?;
+                items[i * n + j] = value / previous_pivot;
+            }
+        }
+        previous_pivot = pivot;
+    }
+    items[n * n - 1].checked_mul(sign)
+}
+
+/// # Numeric
+/// Element types with their own way of calculating a determinant<br>
+/// Integers use the exact Bareiss algorithm, floats use LU decomposition
+/// ```
+/// use math_matrix::Numeric;
+///
+/// assert_eq!(i64::determinant(&[2, -3, 1, 2, 0, -1, 1, 4, 5]).unwrap(), 49);
+/// assert!((f64::determinant(&[9.0, 8.0, 4.0, 8.0, 3.0, 2.0, 4.0, 3.0, 2.0]).unwrap() + 16.0).abs() < 1e-10);
+/// ```
+pub trait Numeric: Copy {
+    /// Determinant of `items` in row by row order<br>
+    /// Returns [`Result`], [`Ok`] if the number of items is a perfect square and the value fits the type, [`Err`] otherwise
+    fn determinant(items: &[Self]) -> Result<Self, Errors>;
+}
+
+/// Size of a square made of `len` items
+fn square_size(len: usize) -> Result<usize, Errors> {
+    let size = (len as f64).sqrt() as usize;
+    if size * size != len {
+        return Err(Errors::InappropriateNumberOfItems);
+    }
+    Ok(size)
+}
+
+impl Numeric for i64 {
+    fn determinant(items: &[i64]) -> Result<i64, Errors> {
+        let size = square_size(items.len())?;
+        let items = items.iter().map(|&item| item as i128).collect();
+        bareiss(items, size)
+            .and_then(|value| i64::try_from(value).ok())
+            .ok_or(Errors::Overflow)
+    }
+}
+
+impl Numeric for f64 {
+    fn determinant(items: &[f64]) -> Result<f64, Errors> {
+        let size = square_size(items.len())?;
+        if size == 0 {
+            return Ok(0.0);
+        }
+        Ok(Lu::new(items, size).determinant())
+    }
+}
+
+/// LU decomposition with partial pivoting of a square matrix in row by row order<br>
+/// `items` holds `U` on and above the diagonal and the multipliers of `L` below it<br>
+/// `permutation[i]` is the original row that ended up at row `i`
+pub(crate) struct Lu {
+    pub(crate) items: Vec<f64>,
+    pub(crate) size: usize,
+    pub(crate) permutation: Vec<usize>,
+    pub(crate) swaps: usize,
+    /// Largest absolute item of the factorized matrix
+    pub(crate) scale: f64,
+}
+impl Lu {
+    pub(crate) fn new(items: &[f64], size: usize) -> Lu {
+        let scale = items.iter().fold(0.0_f64, |max, item| max.max(item.abs()));
+        let mut items = items.to_vec();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps = 0;
+        for k in 0..size {
+            // pick the largest pivot for stability
+            let pivot_row = (k..size)
+                .max_by(|&a, &b| {
+                    items[a * size + k]
+                        .abs()
+                        .total_cmp(&items[b * size + k].abs())
                 })
-                .map(|(_, x)| *x)
-                .collect(),
-        );
-        let sign = if i % 2 == 0 { -1.0 } else { 1.0 };
-        let sign = if j % 2 == 0 { -sign } else { sign };
-        Ok(minor * sign)
+                .expect("Impossible");
+            if pivot_row != k {
+                for c in 0..size {
+                    items.swap(k * size + c, pivot_row * size + c);
+                }
+                permutation.swap(k, pivot_row);
+                swaps += 1;
+            }
+            let pivot = items[k * size + k];
+            if pivot == 0.0 {
+                // nothing to eliminate, the column is already zero
+                continue;
+            }
+            for i in k + 1..size {
+                let factor = items[i * size + k] / pivot;
+                items[i * size + k] = factor;
+                for j in k + 1..size {
+                    items[i * size + j] -= factor * items[k * size + j];
+                }
+            }
+        }
+        Lu {
+            items,
+            size,
+            permutation,
+            swaps,
+            scale,
+        }
+    }
+    /// Whether any pivot is within `epsilon` times the largest item of zero<br>
+    /// Relative to the largest item, so uniformly tiny matrices are not singular
+    pub(crate) fn is_singular(&self, epsilon: f64) -> bool {
+        let tolerance = epsilon * self.scale;
+        (0..self.size).any(|k| self.items[k * self.size + k].abs() <= tolerance)
+    }
+    /// Product of the pivots, sign corrected for the row swaps
+    pub(crate) fn determinant(&self) -> f64 {
+        let product: f64 = (0..self.size)
+            .map(|k| self.items[k * self.size + k])
+            .product();
+        if self.swaps.is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+    }
+    /// Solves `A x = b` by forward and back substitution<br>
+    /// The factorization must not be singular
+    pub(crate) fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let mut x: Vec<f64> = self.permutation.iter().map(|&p| b[p]).collect();
+        self.substitute(&mut x, 1, 0);
+        x
+    }
+    /// Writes the inverse into `out` in row by row order, one column at a time<br>
+    /// The factorization must not be singular
+    pub(crate) fn inverse_into(&self, out: &mut [f64]) {
+        let n = self.size;
+        for column in 0..n {
+            for (i, &p) in self.permutation.iter().enumerate() {
+                out[i * n + column] = if p == column { 1.0 } else { 0.0 };
+            }
+            self.substitute(out, n, column);
+        }
+    }
+    /// Forward and back substitution in place on the vector `x[i * stride + offset]`
+    fn substitute(&self, x: &mut [f64], stride: usize, offset: usize) {
+        let n = self.size;
+        for i in 0..n {
+            for j in 0..i {
+                x[i * stride + offset] -= self.items[i * n + j] * x[j * stride + offset];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i * stride + offset] -= self.items[i * n + j] * x[j * stride + offset];
+            }
+            x[i * stride + offset] /= self.items[i * n + i];
+        }
     }
 }
 
@@ -143,4 +405,190 @@ mod tests {
             -283039494.0
         );
     }
+
+    #[test]
+    fn value_exact_i128() {
+        use crate::Determinant;
+        let d = Determinant::new(vec![
+            3.0, 2.0, 0.0, 1.0, 4.0, 0.0, 1.0, 2.0, 3.0, 0.0, 2.0, 1.0, 9.0, 2.0, 3.0, 1.0,
+        ])
+        .expect("Impossible");
+        assert_eq!(d.value_exact_i128(), Some(24));
+
+        // needs a row swap to find a non zero pivot
+        let d = Determinant::new(vec![0.0, 1.0, 1.0, 0.0]).expect("Impossible");
+        assert_eq!(d.value_exact_i128(), Some(-1));
+
+        let d = Determinant::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0])
+            .expect("Impossible");
+        assert_eq!(d.value_exact_i128(), Some(0));
+
+        let d = Determinant::new(vec![1.5, 2.0, 3.0, 4.0]).expect("Impossible");
+        assert_eq!(d.value_exact_i128(), None);
+    }
+
+    #[test]
+    fn cofactor() {
+        use crate::Determinant;
+        let d = Determinant::new(vec![1.0, 2.0, 3.0, 4.0]).expect("Impossible");
+        assert_eq!(d.cofactor(1, 1).expect("Impossible"), 4.0);
+        assert_eq!(d.cofactor(1, 2).expect("Impossible"), -3.0);
+        assert_eq!(d.cofactor(2, 1).expect("Impossible"), -2.0);
+        assert_eq!(d.cofactor(2, 2).expect("Impossible"), 1.0);
+
+        let d = Determinant::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0])
+            .expect("Impossible");
+        assert_eq!(d.cofactor(1, 2).expect("Impossible"), 6.0);
+        assert_eq!(d.cofactor(2, 2).expect("Impossible"), -12.0);
+        assert_eq!(d.cofactor(3, 1).expect("Impossible"), -3.0);
+        assert!(d.cofactor(0, 1).is_err());
+        assert!(d.cofactor(1, 4).is_err());
+    }
+
+    #[test]
+    fn value_large() {
+        use crate::Determinant;
+        // 10x10 with 2 on the diagonal and -1 next to it, the determinant is n + 1
+        let size: usize = 10;
+        let items = (0..size * size)
+            .map(|x| match (x / size).abs_diff(x % size) {
+                0 => 2.0,
+                1 => -1.0,
+                _ => 0.0,
+            })
+            .collect();
+        let d = Determinant::new(items).expect("Impossible");
+        assert_eq!(d.value(), 11.0);
+    }
+
+    #[test]
+    fn sign() {
+        use crate::{Determinant, Errors};
+        // 400x400 diagonal of 1000 with one negative item, the value is -10^1200
+        let size: usize = 400;
+        let items = (0..size * size)
+            .map(|x| match (x / size, x % size) {
+                (0, 0) => -1000.0,
+                (row, column) if row == column => 1000.0,
+                _ => 0.0,
+            })
+            .collect();
+        let d = Determinant::new(items).expect("Impossible");
+        assert!(1000.0_f64.powi(size as i32).is_infinite());
+        assert_eq!(d.sign().expect("Impossible"), -1.0);
+
+        // the value underflows to zero but the sign is still there
+        let tiny = Determinant::new(vec![0.0, 1e-200, 1e-200, 0.0]).expect("Impossible");
+        assert_eq!(tiny.value(), 0.0);
+        assert_eq!(tiny.sign().expect("Impossible"), -1.0);
+
+        let d = Determinant::new(vec![2.0, 4.0, 1.0, 2.0]).expect("Impossible");
+        assert_eq!(d.sign().expect("Impossible"), 0.0);
+        assert!(matches!(
+            Determinant::new(vec![]).expect("Impossible").sign(),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+    }
+
+    #[test]
+    fn cofactor_sign() {
+        use crate::cofactor_sign;
+        assert_eq!(cofactor_sign(1, 1), 1.0);
+        assert_eq!(cofactor_sign(1, 2), -1.0);
+        assert_eq!(cofactor_sign(2, 1), -1.0);
+        assert_eq!(cofactor_sign(2, 2), 1.0);
+        assert_eq!(cofactor_sign(4, 7), -1.0);
+    }
+
+    #[test]
+    fn value_beyond_recursion_limit() {
+        use crate::Determinant;
+        // 12x12 with 2 on the diagonal and -1 next to it, the determinant is n + 1
+        let size: usize = 12;
+        let items = (0..size * size)
+            .map(|x| match (x / size).abs_diff(x % size) {
+                0 => 2.0,
+                1 => -1.0,
+                _ => 0.0,
+            })
+            .collect();
+        let d = Determinant::new(items).expect("Impossible");
+        assert!((d.value() - 13.0).abs() < 1e-9);
+        // the minors are 11x11 too
+        assert!((d.cofactor(1, 1).expect("Impossible") - 12.0).abs() < 1e-9);
+
+        let mut items = vec![0.0; 15 * 15];
+        items[0] = 1.0;
+        assert_eq!(Determinant::new(items).expect("Impossible").value(), 0.0);
+    }
+
+    #[test]
+    fn value_3x3() {
+        use crate::determinants::Lu;
+        use crate::Determinant;
+        let cases = [
+            vec![9.0, 8.0, 4.0, 8.0, 3.0, 2.0, 4.0, 3.0, 2.0],
+            vec![2.0, -3.0, 1.0, 2.0, 0.0, -1.0, 1.0, 4.0, 5.0],
+            vec![0.5, 1.25, -2.0, 3.0, 0.0, 4.5, -1.0, 2.0, 0.75],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        ];
+        for items in cases {
+            let d = Determinant::new(items.clone()).expect("Impossible");
+            // expansion along the first column with the 2x2 minors
+            let expanded: f64 = (1..=3)
+                .map(|i| items[(i as usize - 1) * 3] * d.cofactor(i, 1).expect("Impossible"))
+                .sum();
+            assert!((d.value() - expanded).abs() < 1e-12);
+            assert!((d.value() - Lu::new(&items, 3).determinant()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn cofactor_expansion_matches_value() {
+        use crate::{Determinant, Matrix};
+        let items = vec![
+            2.0, -1.0, 0.0, 3.0, 1.0, 4.0, -2.0, 0.5, 0.0, 3.0, 5.0, -1.0, 6.0, 0.0, 1.0, 2.0,
+        ];
+        let d = Determinant::new(items.clone()).expect("Impossible");
+        // expansion along the first column through the public cofactors
+        let expanded: f64 = (1..=4)
+            .map(|i| items[(i as usize - 1) * 4] * d.cofactor(i, 1).expect("Impossible"))
+            .sum();
+        assert!((d.value() - expanded).abs() < 1e-9);
+        // and along the second row, where the signs start negative
+        let expanded: f64 = (1..=4)
+            .map(|j| items[4 + j as usize - 1] * d.cofactor(2, j).expect("Impossible"))
+            .sum();
+        assert!((d.value() - expanded).abs() < 1e-9);
+
+        // a wrong sign would show up in the adjoint
+        let matrix = Matrix::new(items, (4, 4)).expect("Impossible");
+        let product = matrix.clone() * matrix.inverse().expect("Impossible");
+        assert!(product.approx_eq(&Matrix::identity_matrix(4)));
+    }
+
+    #[test]
+    fn numeric_determinant() {
+        use crate::{Errors, Numeric};
+        // the items are too big to be exact as f64, but the determinant is -1
+        let big = 1_i64 << 40;
+        let items = [big + 1, big, big, big - 1];
+        assert_eq!(i64::determinant(&items).expect("Impossible"), -1);
+        assert_eq!(i64::determinant(&[0, 1, 1, 0]).expect("Impossible"), -1);
+        assert!(matches!(
+            i64::determinant(&[i64::MAX, 0, 0, i64::MAX]),
+            Err(Errors::Overflow)
+        ));
+        assert!(matches!(
+            i64::determinant(&[1, 2, 3]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+
+        let items = [0.5, 1.25, -2.0, 3.0, 0.0, 4.5, -1.0, 2.0, 0.75];
+        let det = f64::determinant(&items).expect("Impossible");
+        let expected = crate::Determinant::new(items.to_vec())
+            .expect("Impossible")
+            .value();
+        assert!((det - expected).abs() < 1e-12);
+    }
 }
@@ -2,20 +2,48 @@ use std::fmt::{Debug, Display};
 
 pub mod determinants;
 pub mod matrices;
+pub mod views;
 
 pub use crate::determinants::*;
 pub use crate::matrices::*;
+pub use crate::views::*;
+
+/// # Default epsilon
+/// Tolerance used by the methods comparing floats, the `*_with_tolerance` variants accept a custom one
+pub const DEFAULT_EPSILON: f64 = 1e-10;
 
 /// # Errors
 /// * `InappropriateNumberOfItems` - Inappropriate number of items
 /// * `TraceExistsOnlyForSquareMatrices` - Traces exists only for square matrices
 /// * `IncorrectOrdersForOperation` - Incorret orders of matrices for algebric operations
 /// * `IndexOutOfRange` - Index out of range
+/// * `ZeroVector` - Vector must be non zero
+/// * `DivisionByZero` - Division by zero
+/// * `SingularMatrix` - Matrix is singular
+/// * `NotTridiagonal` - Matrix is not tridiagonal
+/// * `InvalidPermutation` - Invalid permutation
+/// * `NotConverged` - Iteration did not converge
+/// * `NotDiagonalizable` - Matrix is not diagonalizable
+/// * `IllConditioned` - Matrix is ill conditioned
+/// * `NotStochastic` - Matrix is not stochastic
+/// * `NotBlockTriangular` - Matrix is not block triangular
+/// * `Overflow` - Arithmetic overflow
 pub enum Errors {
     InappropriateNumberOfItems,
     TraceExistsOnlyForSquareMatrices,
     IncorrectOrdersForOperation,
     IndexOutOfRange,
+    ZeroVector,
+    DivisionByZero,
+    SingularMatrix,
+    NotTridiagonal,
+    InvalidPermutation,
+    NotConverged,
+    NotDiagonalizable,
+    IllConditioned,
+    NotStochastic,
+    NotBlockTriangular,
+    Overflow,
 }
 impl Display for Errors {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -26,6 +54,17 @@ impl Display for Errors {
                 "Incorrect orders of matrices for algebric operations"
             }
             Errors::IndexOutOfRange => "Index out of range",
+            Errors::ZeroVector => "Vector must be non zero",
+            Errors::DivisionByZero => "Division by zero",
+            Errors::SingularMatrix => "Matrix is singular",
+            Errors::NotTridiagonal => "Matrix is not tridiagonal",
+            Errors::InvalidPermutation => "Invalid permutation",
+            Errors::NotConverged => "Iteration did not converge",
+            Errors::NotDiagonalizable => "Matrix is not diagonalizable",
+            Errors::IllConditioned => "Matrix is ill conditioned",
+            Errors::NotStochastic => "Matrix is not stochastic",
+            Errors::NotBlockTriangular => "Matrix is not block triangular",
+            Errors::Overflow => "Arithmetic overflow",
         })
     }
 }
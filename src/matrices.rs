@@ -1,4 +1,5 @@
-use crate::{Determinant, Errors};
+use crate::determinants::Lu;
+use crate::{Determinant, Errors, MatrixView, DEFAULT_EPSILON};
 use std::fmt::Display;
 use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 
@@ -143,6 +144,22 @@ pub struct Matrix {
     pub order: (u32, u32),
 }
 
+/// # Row operation
+/// The elementary row operations, rows start from 1
+/// * `Swap(i, j)` - Swap rows `i` and `j`
+/// * `Scale(i, factor)` - Multiply row `i` by `factor`
+/// * `AddScaled { target, source, factor }` - Add `factor` times row `source` to another row `target`
+#[derive(Clone, Copy, PartialEq)]
+pub enum RowOp {
+    Swap(u32, u32),
+    Scale(u32, f64),
+    AddScaled {
+        target: u32,
+        source: u32,
+        factor: f64,
+    },
+}
+
 impl Matrix {
     /// # Matrix Constructor
     /// ```
@@ -155,10 +172,59 @@ impl Matrix {
     /// assert_eq!(invalid_matrix.is_ok(), false);
     /// ```
     pub fn new(items: Vec<f64>, order: (u32, u32)) -> Result<Matrix, Errors> {
-        if items.len() as u32 != order.0 * order.1 {
+        Matrix::validate(items.len(), order)?;
+        Ok(Matrix { items, order })
+    }
+    /// # Matrix from an iterator
+    /// Collect the items in row by row order<br>
+    /// Returns [`Result`], [`Ok`] if the number of items fits the order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::from_iter_with_order((1..=4).map(|x| x as f64), (2, 2));
+    /// let invalid_matrix = Matrix::from_iter_with_order((1..=5).map(|x| x as f64), (2, 2));
+    ///
+    /// assert!(matrix.unwrap() == Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap());
+    /// assert_eq!(invalid_matrix.is_ok(), false);
+    /// ```
+    pub fn from_iter_with_order<I>(iter: I, order: (u32, u32)) -> Result<Matrix, Errors>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        Matrix::new(iter.into_iter().collect(), order)
+    }
+    /// # Matrix from columns
+    /// Every vector becomes a column of the matrix<br>
+    /// Returns [`Result`], [`Ok`] if all the columns have the same length, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::from_columns(vec![vec![1.0, 3.0], vec![2.0, 4.0]]).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap());
+    /// assert_eq!(Matrix::from_columns(vec![vec![1.0], vec![2.0, 4.0]]).is_ok(), false);
+    /// ```
+    pub fn from_columns(columns: Vec<Vec<f64>>) -> Result<Matrix, Errors> {
+        let rows = columns.first().map_or(0, |column| column.len());
+        if columns.iter().any(|column| column.len() != rows) {
             return Err(Errors::InappropriateNumberOfItems);
         }
-        Ok(Matrix { items, order })
+        Ok(Matrix::generate(
+            |i, j| columns[j as usize - 1][i as usize - 1],
+            (rows as u32, columns.len() as u32),
+        ))
+    }
+    /// # Validate an order
+    /// Check if a number of items fits the order<br>
+    /// Returns [`Result`], [`Ok`] if it fits, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// assert_eq!(Matrix::validate(6, (3, 2)).is_ok(), true);
+    /// assert_eq!(Matrix::validate(3, (3, 2)).is_ok(), false);
+    /// ```
+    pub fn validate(items_len: usize, order: (u32, u32)) -> Result<(), Errors> {
+        if items_len != order.0 as usize * order.1 as usize {
+            return Err(Errors::InappropriateNumberOfItems);
+        }
+        Ok(())
     }
     /// # Matrix generated with function
     /// Using functions to describe the matrix
@@ -190,6 +256,42 @@ impl Matrix {
 
         Matrix { items, order }
     }
+    /// # Generate, 0 based
+    /// Same as [`Matrix::generate`] with `i` and `j` starting from 0
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::generate_0based(|i, j| (i * 3 + j) as f64, (2, 3));
+    ///
+    /// assert!(matrix == Matrix::new(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], (2, 3)).unwrap());
+    /// ```
+    pub fn generate_0based<F>(f: F, order: (u32, u32)) -> Matrix
+    where
+        F: Fn(u32, u32) -> f64,
+    {
+        Matrix::generate(|i, j| f(i - 1, j - 1), order)
+    }
+    /// # Generate in parallel
+    /// Same as [`Matrix::generate`], computing the elements on the rayon thread pool<br>
+    /// Worth it when `f` is expensive, requires the `rayon` feature
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let generated = Matrix::generate_parallel(|i, j| (i * 10 + j) as f64, (2, 3));
+    ///
+    /// assert!(generated == Matrix::generate(|i, j| (i * 10 + j) as f64, (2, 3)));
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn generate_parallel<F>(f: F, order: (u32, u32)) -> Matrix
+    where
+        F: Fn(u32, u32) -> f64 + Sync,
+    {
+        use rayon::prelude::*;
+        let items = (0..order.0 * order.1)
+            .into_par_iter()
+            .map(|x| f(x / order.1 + 1, x % order.1 + 1))
+            .collect();
+
+        Matrix { items, order }
+    }
     /// # Row matrix
     /// eg. `1  2  3  4  5  6  7`
     /// ```
@@ -314,6 +416,20 @@ impl Matrix {
     pub fn identity_matrix(size: u32) -> Matrix {
         Matrix::scalar_matrix(1.0, size)
     }
+    /// # Eye
+    /// Rectangular identity, ones on the main diagonal and zeros everywhere else
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let eye = Matrix::eye((2, 3));
+    /// // 1  0  0
+    /// // 0  1  0
+    ///
+    /// assert!(eye == Matrix::new(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0], (2, 3)).unwrap());
+    /// assert!(Matrix::eye((4, 4)) == Matrix::identity_matrix(4));
+    /// ```
+    pub fn eye(order: (u32, u32)) -> Matrix {
+        Matrix::generate(|i, j| if i == j { 1.0 } else { 0.0 }, order)
+    }
     /// # Trace
     /// Traces are the diagonal items of a square matrix<br>
     /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise<br>
@@ -351,6 +467,30 @@ impl Matrix {
             .map(|(_, e)| e)
             .collect())
     }
+    /// # Rectangular trace
+    /// Sum of the items `(i, i)` for `i` up to the smaller dimension, so it also works on non square matrices<br>
+    /// Equal to the sum of [`Matrix::trace`] for square matrices
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    /// assert_eq!(matrix.trace_rectangular(), 6.0);
+    /// ```
+    pub fn trace_rectangular(&self) -> f64 {
+        (1..=self.order.0.min(self.order.1))
+            .map(|k| self[(k, k)])
+            .sum()
+    }
+    /// # Diagonal product
+    /// Product of the diagonal items of a square matrix, equal to the determinant for triangular matrices<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![2.0, 7.0, 1.0, 0.0, 3.0, 5.0, 0.0, 0.0, 4.0], (3, 3)).unwrap();
+    /// assert_eq!(matrix.diagonal_product().unwrap(), 24.0);
+    /// ```
+    pub fn diagonal_product(&self) -> Result<f64, Errors> {
+        Ok(self.trace()?.iter().product())
+    }
     /// # Transpose
     /// Flip the rows and columns
     /// ```
@@ -370,6 +510,27 @@ impl Matrix {
             (self.order.1, self.order.0),
         )
     }
+    /// # Transpose
+    /// Flip the rows and columns and update the matrix<br>
+    /// Square matrices are transposed in place, other matrices are rebuilt
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.transpose_mut();
+    /// assert!(matrix == Matrix::new(vec![1.0, 3.0, 2.0, 4.0], (2, 2)).unwrap());
+    /// ```
+    pub fn transpose_mut(&mut self) {
+        if self.order.0 != self.order.1 {
+            *self = self.transpose();
+            return;
+        }
+        let n = self.order.0 as usize;
+        for i in 0..n {
+            for j in (i + 1)..n {
+                self.items.swap(i * n + j, j * n + i);
+            }
+        }
+    }
     /// # Determinant
     /// Convert the matrix into a determinant
     /// ```
@@ -392,6 +553,34 @@ impl Matrix {
         }
         Determinant::new(self.items.clone())
     }
+    /// # Into determinant
+    /// Same as [`Matrix::to_determinant`], moving the items instead of cloning them<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![9.0, 8.0, 4.0, 8.0, 3.0, 2.0, 4.0, 3.0, 2.0], (3, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.into_determinant().unwrap().value(), -16.0);
+    /// ```
+    pub fn into_determinant(self) -> Result<Determinant, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Determinant::new(self.items)
+    }
+    /// # Determinant value
+    /// Shortcut for `to_determinant()?.value()`<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![9.0, 8.0, 4.0, 8.0, 3.0, 2.0, 4.0, 3.0, 2.0], (3, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.det().unwrap(), -16.0);
+    /// assert_eq!(Matrix::null_matrix((2, 3)).det().is_err(), true);
+    /// ```
+    pub fn det(&self) -> Result<f64, Errors> {
+        Ok(self.to_determinant()?.value())
+    }
     /// # Adjoint
     /// Get the adjoint of a matrix
     /// ```
@@ -407,7 +596,8 @@ impl Matrix {
         )
     }
     /// # Inverse
-    /// Get the inverse of a matrix
+    /// Get the inverse of a matrix<br>
+    /// Diagonal matrices invert by reciprocating the diagonal and triangular ones by back substitution, others from the adjoint
     /// ```
     /// use math_matrix::Matrix;
     /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0], (3, 3)).unwrap();
@@ -415,7 +605,46 @@ impl Matrix {
     /// assert!(matrix.inverse().unwrap() == inverse);
     /// ```
     pub fn inverse(&self) -> Result<Matrix, Errors> {
-        Ok(self.adjoint()? / self.to_determinant()?.value())
+        let n = self.order.0;
+        if self.order.1 == n && (1..=n).all(|k| self[(k, k)] != 0.0) {
+            let upper = (1..=n).all(|i| (1..i).all(|j| self[(i, j)] == 0.0));
+            let lower = (1..=n).all(|i| (i + 1..=n).all(|j| self[(i, j)] == 0.0));
+            match (upper, lower) {
+                (true, true) => {
+                    return Ok(Matrix::generate(
+                        |i, j| if i == j { 1.0 / self[(i, i)] } else { 0.0 },
+                        self.order,
+                    ))
+                }
+                (true, false) => return Ok(self.upper_triangular_inverse()),
+                (false, true) => {
+                    return Ok(self.transpose().upper_triangular_inverse().transpose())
+                }
+                (false, false) => {}
+            }
+        }
+        self.adjoint_inverse()
+    }
+    /// Inverse from the adjoint, for any invertible matrix
+    fn adjoint_inverse(&self) -> Result<Matrix, Errors> {
+        Ok(self.adjoint()? / self.det()?)
+    }
+    /// Inverse of an upper triangular matrix with a non zero diagonal by back substitution of every column
+    fn upper_triangular_inverse(&self) -> Matrix {
+        let n = self.order.0 as usize;
+        let a = |i: usize, j: usize| self.items[i * n + j];
+        let mut items = vec![0.0; n * n];
+        for j in 0..n {
+            items[j * n + j] = 1.0 / a(j, j);
+            for i in (0..j).rev() {
+                let sum: f64 = (i + 1..=j).map(|k| a(i, k) * items[k * n + j]).sum();
+                items[i * n + j] = -sum / a(i, i);
+            }
+        }
+        Matrix {
+            items,
+            order: self.order,
+        }
     }
     /// # Round
     /// Round of all the elements of the matrix
@@ -444,6 +673,45 @@ impl Matrix {
             self.order,
         );
     }
+    /// # Floor
+    /// Round down all the elements of the matrix
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![-1.5, 0.25, 1.75], (1, 3)).unwrap();
+    /// assert!(matrix.floor() == Matrix::new(vec![-2.0, 0.0, 1.0], (1, 3)).unwrap());
+    /// ```
+    pub fn floor(&self) -> Matrix {
+        Matrix::generate(
+            |i, j| self.get(i, j).expect("Impossible").floor(),
+            self.order,
+        )
+    }
+    /// # Ceil
+    /// Round up all the elements of the matrix
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![-1.5, 0.25, 1.75], (1, 3)).unwrap();
+    /// assert!(matrix.ceil() == Matrix::new(vec![-1.0, 1.0, 2.0], (1, 3)).unwrap());
+    /// ```
+    pub fn ceil(&self) -> Matrix {
+        Matrix::generate(
+            |i, j| self.get(i, j).expect("Impossible").ceil(),
+            self.order,
+        )
+    }
+    /// # Truncate
+    /// Round all the elements of the matrix toward zero
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![-1.5, 0.25, 1.75], (1, 3)).unwrap();
+    /// assert!(matrix.trunc() == Matrix::new(vec![-1.0, 0.0, 1.0], (1, 3)).unwrap());
+    /// ```
+    pub fn trunc(&self) -> Matrix {
+        Matrix::generate(
+            |i, j| self.get(i, j).expect("Impossible").trunc(),
+            self.order,
+        )
+    }
     /// # Is the matrix horizontal?
     /// ```
     /// use math_matrix::Matrix;
@@ -474,6 +742,26 @@ impl Matrix {
     pub fn is_vertical(&self) -> bool {
         self.order.0 > self.order.1
     }
+    /// # Number of elements
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::null_matrix((3, 2));
+    ///
+    /// assert_eq!(matrix.len(), 6);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.order.0 as usize * self.order.1 as usize
+    }
+    /// # Is the matrix empty?
+    /// Only when one of the dimensions of the order is 0
+    /// ```
+    /// use math_matrix::Matrix;
+    /// assert!(Matrix::null_matrix((0, 3)).is_empty());
+    /// assert!(!Matrix::null_matrix((1, 1)).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     /// # Get an item from the matrix
     /// ```
     /// use math_matrix::Matrix;
@@ -492,6 +780,18 @@ impl Matrix {
             None => Err(Errors::IndexOutOfRange),
         }
     }
+    /// # Get an item from the matrix by position
+    /// Same as [`Matrix::get`] with the coordinates as a single `(i, j)` value, like the `Index` impl
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// let position = (2, 1);
+    ///
+    /// assert_eq!(matrix.get_at(position).unwrap(), 3.0);
+    /// ```
+    pub fn get_at(&self, (i, j): (u32, u32)) -> Result<f64, Errors> {
+        self.get(i, j)
+    }
     /// # Get an entire row
     /// ```
     /// use math_matrix::Matrix;
@@ -578,213 +878,4997 @@ impl Matrix {
             None => Err(Errors::IndexOutOfRange),
         }
     }
-}
-impl Add for Matrix {
-    type Output = Matrix;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        if self.order != rhs.order {
-            eprintln!("Error: {}", Errors::IncorrectOrdersForOperation);
-            panic!();
-        }
-        Matrix::generate(
-            |i, j| self.get(i, j).expect("Impossible") + rhs.get(i, j).expect("Impossible"),
-            self.order,
-        )
+    /// # Set an item of the matrix by position
+    /// Same as [`Matrix::set`] with the coordinates as a single `(i, j)` value, like the `IndexMut` impl
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.set_at((1, 2), 9.0).unwrap();
+    ///
+    /// assert_eq!(matrix.get_at((1, 2)).unwrap(), 9.0);
+    /// assert_eq!(matrix.set_at((3, 1), 9.0).is_err(), true);
+    /// ```
+    pub fn set_at(&mut self, (i, j): (u32, u32), new_value: f64) -> Result<(), Errors> {
+        self.set(i, j, new_value)
     }
-}
-impl AddAssign for Matrix {
-    fn add_assign(&mut self, rhs: Self) {
-        if self.order != rhs.order {
-            eprintln!("Error: {}", Errors::IncorrectOrdersForOperation);
-            panic!();
+    /// # Set many items of the matrix
+    /// Apply every `((i, j), value)` update in order, after checking all the positions<br>
+    /// Returns [`Result`], [`Ok`] if every position is in range, [`Err`] otherwise without changing anything
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::null_matrix((2, 2));
+    /// matrix.set_many(&[((1, 1), 1.0), ((2, 2), 4.0)]).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![1.0, 0.0, 0.0, 4.0], (2, 2)).unwrap());
+    /// assert_eq!(matrix.set_many(&[((1, 2), 2.0), ((3, 1), 9.0)]).is_err(), true);
+    /// assert_eq!(matrix[(1, 2)], 0.0);
+    /// ```
+    pub fn set_many(&mut self, updates: &[((u32, u32), f64)]) -> Result<(), Errors> {
+        if updates
+            .iter()
+            .any(|&((i, j), _)| i == 0 || i > self.order.0 || j == 0 || j > self.order.1)
+        {
+            return Err(Errors::IndexOutOfRange);
         }
-        *self = Matrix::generate(
-            |i, j| self.get(i, j).expect("Impossible") + rhs.get(i, j).expect("Impossible"),
-            self.order,
-        );
+        for &(position, new_value) in updates {
+            self[position] = new_value;
+        }
+        Ok(())
     }
-}
-impl Sub for Matrix {
-    type Output = Matrix;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        if self.order != rhs.order {
-            eprintln!("Error: {}", Errors::IncorrectOrdersForOperation);
-            panic!();
+    /// # Get an item from the matrix, 0 based
+    /// Same as [`Matrix::get`] with `i` and `j` starting from 0
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.get0(0, 1).unwrap(), 2.0);
+    /// assert_eq!(matrix.get0(0, 2).is_err(), true);
+    /// ```
+    pub fn get0(&self, i: u32, j: u32) -> Result<f64, Errors> {
+        if i >= self.order.0 || j >= self.order.1 {
+            return Err(Errors::IndexOutOfRange);
         }
-        Matrix::generate(
-            |i, j| self.get(i, j).expect("Impossible") - rhs.get(i, j).expect("Impossible"),
-            self.order,
-        )
+        self.get(i + 1, j + 1)
     }
-}
-impl SubAssign for Matrix {
-    fn sub_assign(&mut self, rhs: Self) {
-        if self.order != rhs.order {
-            eprintln!("Error: {}", Errors::IncorrectOrdersForOperation);
-            panic!();
+    /// # Set an item of the matrix, 0 based
+    /// Same as [`Matrix::set`] with `i` and `j` starting from 0
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.set0(1, 0, 9.0).unwrap();
+    ///
+    /// assert_eq!(matrix.get(2, 1).unwrap(), 9.0);
+    /// ```
+    pub fn set0(&mut self, i: u32, j: u32, new_value: f64) -> Result<(), Errors> {
+        if i >= self.order.0 || j >= self.order.1 {
+            return Err(Errors::IndexOutOfRange);
         }
-        *self = Matrix::generate(
-            |i, j| self.get(i, j).expect("Impossible") - rhs.get(i, j).expect("Impossible"),
-            self.order,
-        );
+        self.set(i + 1, j + 1, new_value)
     }
-}
-#[allow(clippy::suspicious_arithmetic_impl)]
-impl Mul for Matrix {
-    type Output = Matrix;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        if self.order.1 != rhs.order.0 {
-            eprintln!("Error: {}", Errors::IncorrectOrdersForOperation);
-            panic!();
+    /// # Get an entire row, 0 based
+    /// Same as [`Matrix::get_row`] with `i` starting from 0
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.get_row0(0).unwrap(), vec![1.0, 2.0]);
+    /// ```
+    pub fn get_row0(&self, i: u32) -> Result<Vec<f64>, Errors> {
+        self.get_row(i.checked_add(1).ok_or(Errors::IndexOutOfRange)?)
+    }
+    /// # Get an entire column, 0 based
+    /// Same as [`Matrix::get_column`] with `j` starting from 0
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.get_column0(0).unwrap(), vec![1.0, 3.0]);
+    /// ```
+    pub fn get_column0(&self, j: u32) -> Result<Vec<f64>, Errors> {
+        self.get_column(j.checked_add(1).ok_or(Errors::IndexOutOfRange)?)
+    }
+    /// # Matrix as a nested array
+    /// Returns [`Result`], [`Ok`] if the order of the matrix is `(R, C)`, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.as_array::<2, 3>().unwrap(), [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix.as_array::<3, 2>().is_err(), true);
+    /// ```
+    pub fn as_array<const R: usize, const C: usize>(&self) -> Result<[[f64; C]; R], Errors> {
+        if self.order != (R as u32, C as u32) {
+            return Err(Errors::IncorrectOrdersForOperation);
         }
-        Matrix::generate(
-            |i, j| {
-                let mut sum = 0.0;
-                let a = self.get_row(i).expect("Impossible");
-                let b = rhs.get_column(j).expect("Impossible");
-                for r in 0..self.order.1 {
-                    sum += a[r as usize] * b[r as usize]
-                }
-                sum
-            },
-            (self.order.0, rhs.order.1),
-        )
+        let mut array = [[0.0; C]; R];
+        for (i, row) in array.iter_mut().enumerate() {
+            row.copy_from_slice(&self.items[i * C..(i + 1) * C]);
+        }
+        Ok(array)
     }
-}
-impl Mul<f64> for Matrix {
-    type Output = Matrix;
-
-    fn mul(self, rhs: f64) -> Self::Output {
-        Matrix::generate(|i, j| self.get(i, j).expect("Impossible") * rhs, self.order)
+    /// # Map to another type
+    /// Map every item of the matrix with a function, returning the rows of the result
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.5, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     matrix.map_to(|x| x.to_string()),
+    ///     vec![vec!["1", "2.5"], vec!["3", "4"]]
+    /// );
+    /// ```
+    pub fn map_to<U, F>(&self, f: F) -> Vec<Vec<U>>
+    where
+        F: Fn(f64) -> U,
+    {
+        self.items
+            .chunks(self.order.1.max(1) as usize)
+            .map(|row| row.iter().map(|&x| f(x)).collect())
+            .collect()
     }
-}
-
-#[allow(clippy::suspicious_arithmetic_impl)]
-impl MulAssign for Matrix {
-    fn mul_assign(&mut self, rhs: Self) {
-        if self.order.1 != rhs.order.0 {
-            eprintln!("Error: {}", Errors::IncorrectOrdersForOperation);
-            panic!();
+    /// # Boolean mask
+    /// Map every item of the matrix to a [`bool`], returning the rows of the mask
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, -2.0, 0.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.to_bool_mask(|x| x > 0.0), vec![vec![true, false], vec![false, true]]);
+    /// ```
+    pub fn to_bool_mask<F>(&self, f: F) -> Vec<Vec<bool>>
+    where
+        F: Fn(f64) -> bool,
+    {
+        self.map_to(f)
+    }
+    /// # Householder reflection
+    /// The reflector `H = I - 2 v vᵀ / (vᵀ v)` for a column vector `v`<br>
+    /// Returns [`Result`], [`Ok`] if `v` is a non zero column vector, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let v = Matrix::column_matrix(vec![1.0, 1.0]);
+    /// let h = Matrix::householder(&v).unwrap();
+    /// // 0   -1
+    /// // -1  0
+    /// assert!(h.round() == Matrix::new(vec![0.0, -1.0, -1.0, 0.0], (2, 2)).unwrap());
+    /// ```
+    pub fn householder(vector: &Matrix) -> Result<Matrix, Errors> {
+        if vector.order.1 != 1 {
+            return Err(Errors::IncorrectOrdersForOperation);
         }
-        *self = Matrix::generate(
+        let norm_squared: f64 = vector.items.iter().map(|x| x * x).sum();
+        if norm_squared == 0.0 {
+            return Err(Errors::ZeroVector);
+        }
+        Ok(Matrix::generate(
             |i, j| {
-                let mut sum = 0.0;
-                let a = self.get_row(i).expect("Impossible");
-                let b = rhs.get_column(j).expect("Impossible");
-                for r in 0..self.order.1 {
-                    sum += a[r as usize] * b[r as usize]
-                }
-                sum
+                let identity = if i == j { 1.0 } else { 0.0 };
+                identity
+                    - 2.0 * vector.items[(i - 1) as usize] * vector.items[(j - 1) as usize]
+                        / norm_squared
             },
-            (self.order.0, rhs.order.1),
-        );
+            (vector.order.0, vector.order.0),
+        ))
     }
-}
-impl MulAssign<f64> for Matrix {
-    fn mul_assign(&mut self, rhs: f64) {
-        *self = Matrix::generate(|i, j| self.get(i, j).expect("Impossible") * rhs, self.order);
-    }
-}
-impl Div<f64> for Matrix {
-    type Output = Matrix;
-    fn div(self, rhs: f64) -> Self::Output {
-        Matrix::generate(|i, j| self.get(i, j).expect("Impossible") / rhs, self.order)
+    /// # Givens rotation
+    /// An identity matrix with a rotation by `theta` in the plane of rows/columns `i` and `j`<br>
+    /// Returns [`Result`], [`Ok`] if `i` and `j` are distinct and within the bounds, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let g = Matrix::givens(2, 1, 2, std::f64::consts::FRAC_PI_2).unwrap();
+    /// // 0  -1
+    /// // 1  0
+    /// assert!(g.round() == Matrix::new(vec![0.0, -1.0, 1.0, 0.0], (2, 2)).unwrap());
+    /// assert_eq!(Matrix::givens(3, 1, 4, 1.0).is_err(), true);
+    /// ```
+    pub fn givens(size: u32, i: u32, j: u32, theta: f64) -> Result<Matrix, Errors> {
+        if i == 0 || i > size || j == 0 || j > size || i == j {
+            return Err(Errors::IndexOutOfRange);
+        }
+        let (sin, cos) = theta.sin_cos();
+        let mut matrix = Matrix::identity_matrix(size);
+        matrix[(i, i)] = cos;
+        matrix[(j, j)] = cos;
+        matrix[(i, j)] = -sin;
+        matrix[(j, i)] = sin;
+        Ok(matrix)
     }
-}
-impl DivAssign<f64> for Matrix {
+    /// # Elementwise division
+    /// Divide the corresponding items of two matrices of the same order<br>
+    /// Dividing by a zero item gives infinity or NaN, use [`Matrix::div_elementwise_checked`] to catch it<br>
+    /// Returns [`Result`], [`Ok`] if the orders match, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 9.0, 4.0, 1.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![2.0, 3.0, 8.0, 0.0], (2, 2)).unwrap();
+    /// let quotient = a.div_elementwise(&b).unwrap();
+    ///
+    /// assert_eq!(quotient[(1, 2)], 3.0);
+    /// assert_eq!(quotient[(2, 1)], 0.5);
+    /// assert_eq!(quotient[(2, 2)], f64::INFINITY);
+    /// ```
+    pub fn div_elementwise(&self, rhs: &Matrix) -> Result<Matrix, Errors> {
+        if self.order != rhs.order {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Ok(Matrix {
+            items: self
+                .items
+                .iter()
+                .zip(rhs.items.iter())
+                .map(|(a, b)| a / b)
+                .collect(),
+            order: self.order,
+        })
+    }
+    /// # Checked elementwise division
+    /// Divide the corresponding items of two matrices of the same order<br>
+    /// Returns [`Result`], [`Ok`] if the orders match and `rhs` has no zero items, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 9.0, 4.0, 1.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![2.0, 3.0, 8.0, 0.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(a.div_elementwise_checked(&b).is_err(), true);
+    /// ```
+    pub fn div_elementwise_checked(&self, rhs: &Matrix) -> Result<Matrix, Errors> {
+        if self.order != rhs.order {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        if rhs.items.contains(&0.0) {
+            return Err(Errors::DivisionByZero);
+        }
+        self.div_elementwise(rhs)
+    }
+    /// # Is the matrix singular?
+    /// Checks for a pivot within [`DEFAULT_EPSILON`] times the largest item of zero during row reduction, without calculating the determinant<br>
+    /// Being relative to the largest item, scaling the matrix doesn't change the answer, like [`Determinant::sign`]<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let singular = Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2)).unwrap();
+    /// let invertible = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(singular.is_singular().unwrap(), true);
+    /// assert_eq!(invertible.is_singular().unwrap(), false);
+    /// ```
+    pub fn is_singular(&self) -> Result<bool, Errors> {
+        self.is_singular_with_tolerance(DEFAULT_EPSILON)
+    }
+    /// # Is the matrix singular?
+    /// Checks for a pivot within `epsilon` times the largest item of zero during row reduction, without calculating the determinant<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let almost_singular = Matrix::new(vec![1.0, 2.0, 2.0, 4.001], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(almost_singular.is_singular_with_tolerance(1e-2).unwrap(), true);
+    /// assert_eq!(almost_singular.is_singular_with_tolerance(1e-4).unwrap(), false);
+    /// ```
+    pub fn is_singular_with_tolerance(&self, epsilon: f64) -> Result<bool, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Ok(Lu::new(&self.items, self.order.0 as usize).is_singular(epsilon))
+    }
+    /// # Eigenvalue sanity
+    /// The sum of the trace and the determinant together, which are the sum and the product of the eigenvalues<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![2.0, 1.0, 1.0, 2.0], (2, 2)).unwrap();
+    /// // eigenvalues: 1, 3
+    /// assert_eq!(matrix.eigenvalue_sanity().unwrap(), (4.0, 3.0));
+    /// ```
+    pub fn eigenvalue_sanity(&self) -> Result<(f64, f64), Errors> {
+        let trace_sum = self.trace()?.iter().sum();
+        Ok((trace_sum, self.det()?))
+    }
+    /// # Split at a row
+    /// Split the matrix into the first `i` rows and the remaining rows<br>
+    /// Returns [`Result`], [`Ok`] if `i` is within the bounds, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (3, 2)).unwrap();
+    /// let (top, bottom) = matrix.split_at_row(1).unwrap();
+    ///
+    /// assert!(top == Matrix::row_matrix(vec![1.0, 2.0]));
+    /// assert!(bottom == Matrix::new(vec![3.0, 4.0, 5.0, 6.0], (2, 2)).unwrap());
+    /// assert_eq!(matrix.split_at_row(4).is_err(), true);
+    /// ```
+    pub fn split_at_row(&self, i: u32) -> Result<(Matrix, Matrix), Errors> {
+        if i > self.order.0 {
+            return Err(Errors::IndexOutOfRange);
+        }
+        let (top, bottom) = self.items.split_at((i * self.order.1) as usize);
+        Ok((
+            Matrix {
+                items: top.to_vec(),
+                order: (i, self.order.1),
+            },
+            Matrix {
+                items: bottom.to_vec(),
+                order: (self.order.0 - i, self.order.1),
+            },
+        ))
+    }
+    /// # Split at a column
+    /// Split the matrix into the first `j` columns and the remaining columns<br>
+    /// Returns [`Result`], [`Ok`] if `j` is within the bounds, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    /// let (left, right) = matrix.split_at_column(2).unwrap();
+    ///
+    /// assert!(left == Matrix::new(vec![1.0, 2.0, 4.0, 5.0], (2, 2)).unwrap());
+    /// assert!(right == Matrix::column_matrix(vec![3.0, 6.0]));
+    /// assert_eq!(matrix.split_at_column(4).is_err(), true);
+    /// ```
+    pub fn split_at_column(&self, j: u32) -> Result<(Matrix, Matrix), Errors> {
+        if j > self.order.1 {
+            return Err(Errors::IndexOutOfRange);
+        }
+        let left = Matrix::generate(|r, c| self[(r, c)], (self.order.0, j));
+        let right = Matrix::generate(|r, c| self[(r, c + j)], (self.order.0, self.order.1 - j));
+        Ok((left, right))
+    }
+    /// # Diagonal at an offset
+    /// Items along the `k`th diagonal, `0` is the main diagonal, positive `k` is above it and negative `k` is below it<br>
+    /// Empty if the diagonal lies outside the matrix
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], (3, 3)).unwrap();
+    /// // 1  2  3
+    /// // 4  5  6
+    /// // 7  8  9
+    /// assert_eq!(matrix.diagonal_k(0), vec![1.0, 5.0, 9.0]);
+    /// assert_eq!(matrix.diagonal_k(2), vec![3.0]);
+    /// assert_eq!(matrix.diagonal_k(-1), vec![4.0, 8.0]);
+    /// ```
+    pub fn diagonal_k(&self, k: i32) -> Vec<f64> {
+        match Matrix::diagonal_position(k, self.order) {
+            Some((row_offset, column_offset, length)) => (1..=length)
+                .map(|i| self[(i + row_offset, i + column_offset)])
+                .collect(),
+            None => vec![],
+        }
+    }
+    // row offset, column offset and length of the `k`th diagonal, if it lies inside the order
+    fn diagonal_position(k: i32, order: (u32, u32)) -> Option<(u32, u32, u32)> {
+        let (row_offset, column_offset) = if k >= 0 {
+            (0, k.unsigned_abs())
+        } else {
+            (k.unsigned_abs(), 0)
+        };
+        if row_offset >= order.0 || column_offset >= order.1 {
+            return None;
+        }
+        let length = (order.0 - row_offset).min(order.1 - column_offset);
+        Some((row_offset, column_offset, length))
+    }
+    /// # Matrix from diagonals
+    /// Place each `(k, items)` pair along the `k`th diagonal, every other item is 0<br>
+    /// Returns [`Result`], [`Ok`] if every diagonal lies inside the order and has the right number of items, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::from_diagonals(vec![(0, vec![1.0, 2.0, 3.0]), (1, vec![4.0, 5.0])], (3, 3)).unwrap();
+    /// // 1  4  0
+    /// // 0  2  5
+    /// // 0  0  3
+    /// assert!(matrix == Matrix::new(vec![1.0, 4.0, 0.0, 0.0, 2.0, 5.0, 0.0, 0.0, 3.0], (3, 3)).unwrap());
+    /// assert_eq!(Matrix::from_diagonals(vec![(1, vec![1.0, 2.0, 3.0])], (3, 3)).is_err(), true);
+    /// ```
+    pub fn from_diagonals(
+        diagonals: Vec<(i32, Vec<f64>)>,
+        order: (u32, u32),
+    ) -> Result<Matrix, Errors> {
+        let mut matrix = Matrix::null_matrix(order);
+        for (k, items) in diagonals {
+            let (row_offset, column_offset, length) =
+                Matrix::diagonal_position(k, order).ok_or(Errors::IndexOutOfRange)?;
+            if items.len() != length as usize {
+                return Err(Errors::InappropriateNumberOfItems);
+            }
+            for (i, item) in (1..=length).zip(items) {
+                matrix[(i + row_offset, i + column_offset)] = item;
+            }
+        }
+        Ok(matrix)
+    }
+    /// # Solve a tridiagonal system
+    /// Solve `A x = b` for a tridiagonal `A` and a column matrix `b` with the Thomas algorithm in O(n)<br>
+    /// The algorithm does not pivot, so it is meant for diagonally dominant systems<br>
+    /// Returns [`Result`], [`Ok`] containing `x` if `A` is a square tridiagonal matrix matching `b` and no pivot is zero, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0], (3, 3)).unwrap();
+    /// let b = Matrix::column_matrix(vec![4.0, 8.0, 8.0]);
+    ///
+    /// assert!(a.solve_tridiagonal(&b).unwrap().round() == Matrix::column_matrix(vec![1.0, 2.0, 3.0]));
+    /// ```
+    pub fn solve_tridiagonal(&self, b: &Matrix) -> Result<Matrix, Errors> {
+        self.solve_tridiagonal_with_tolerance(b, DEFAULT_EPSILON)
+    }
+    /// # Solve a tridiagonal system
+    /// Same as [`Matrix::solve_tridiagonal`], treating items within `epsilon` of zero as zero when checking that `A` is tridiagonal
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 1.0, 0.001, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0], (3, 3)).unwrap();
+    /// let b = Matrix::column_matrix(vec![4.0, 8.0, 8.0]);
+    ///
+    /// assert_eq!(a.solve_tridiagonal(&b).is_err(), true);
+    /// assert_eq!(a.solve_tridiagonal_with_tolerance(&b, 1e-2).is_ok(), true);
+    /// ```
+    pub fn solve_tridiagonal_with_tolerance(
+        &self,
+        b: &Matrix,
+        epsilon: f64,
+    ) -> Result<Matrix, Errors> {
+        let n = self.order.0;
+        if self.order.1 != n || b.order != (n, 1) {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        for i in 1..=n {
+            for j in 1..=n {
+                if i.abs_diff(j) > 1 && self[(i, j)].abs() > epsilon {
+                    return Err(Errors::NotTridiagonal);
+                }
+            }
+        }
+
+        // forward sweep
+        let mut c_prime = vec![0.0; n as usize];
+        let mut d_prime = vec![0.0; n as usize];
+        for i in 1..=n {
+            let sub = if i > 1 { self[(i, i - 1)] } else { 0.0 };
+            let (previous_c, previous_d) = if i > 1 {
+                (c_prime[(i - 2) as usize], d_prime[(i - 2) as usize])
+            } else {
+                (0.0, 0.0)
+            };
+            let pivot = self[(i, i)] - sub * previous_c;
+            if pivot == 0.0 {
+                return Err(Errors::SingularMatrix);
+            }
+            if i < n {
+                c_prime[(i - 1) as usize] = self[(i, i + 1)] / pivot;
+            }
+            d_prime[(i - 1) as usize] = (b[(i, 1)] - sub * previous_d) / pivot;
+        }
+
+        // back substitution
+        let mut x = d_prime;
+        for i in (0..(n as usize).saturating_sub(1)).rev() {
+            x[i] -= c_prime[i] * x[i + 1];
+        }
+        Ok(Matrix::column_matrix(x))
+    }
+    /// # Rank
+    /// Number of linearly independent rows, found by row reduction treating pivots within [`DEFAULT_EPSILON`] times the largest item of zero as zero<br>
+    /// Being relative to the largest item, scaling the matrix doesn't change its rank
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0], (2, 3)).unwrap();
+    /// assert_eq!(matrix.rank(), 1);
+    /// assert_eq!(Matrix::identity_matrix(4).rank(), 4);
+    /// ```
+    pub fn rank(&self) -> u32 {
+        self.rank_with_tolerance(DEFAULT_EPSILON)
+    }
+    /// # Rank
+    /// Number of linearly independent rows, found by row reduction treating pivots within `epsilon` times the largest item of zero as zero
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 2.0, 4.001], (2, 2)).unwrap();
+    /// assert_eq!(matrix.rank_with_tolerance(1e-2), 1);
+    /// assert_eq!(matrix.rank_with_tolerance(1e-4), 2);
+    /// ```
+    pub fn rank_with_tolerance(&self, epsilon: f64) -> u32 {
+        let (rows, columns) = (self.order.0 as usize, self.order.1 as usize);
+        let scale = self
+            .items
+            .iter()
+            .fold(0.0_f64, |max, item| max.max(item.abs()));
+        let tolerance = epsilon * scale;
+        let mut items = self.items.clone();
+        let mut rank = 0;
+        for column in 0..columns {
+            if rank == rows {
+                break;
+            }
+            let pivot_row = (rank..rows)
+                .max_by(|&a, &b| {
+                    items[a * columns + column]
+                        .abs()
+                        .total_cmp(&items[b * columns + column].abs())
+                })
+                .expect("Impossible");
+            let pivot = items[pivot_row * columns + column];
+            if pivot.abs() <= tolerance {
+                continue;
+            }
+            for c in 0..columns {
+                items.swap(rank * columns + c, pivot_row * columns + c);
+            }
+            for r in rank + 1..rows {
+                let factor = items[r * columns + column] / pivot;
+                for c in column..columns {
+                    items[r * columns + c] -= factor * items[rank * columns + c];
+                }
+            }
+            rank += 1;
+        }
+        rank as u32
+    }
+    /// # Are the columns linearly independent?
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0], (3, 2)).unwrap();
+    /// assert!(matrix.are_columns_independent());
+    /// ```
+    pub fn are_columns_independent(&self) -> bool {
+        self.are_columns_independent_with_tolerance(DEFAULT_EPSILON)
+    }
+    /// # Are the columns linearly independent?
+    /// Uses `epsilon` as the tolerance of [`Matrix::rank_with_tolerance`]
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 2.0, 4.001], (2, 2)).unwrap();
+    /// assert!(!matrix.are_columns_independent_with_tolerance(1e-2));
+    /// ```
+    pub fn are_columns_independent_with_tolerance(&self, epsilon: f64) -> bool {
+        self.rank_with_tolerance(epsilon) == self.order.1
+    }
+    /// # Are the rows linearly independent?
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0], (2, 3)).unwrap();
+    /// assert!(!matrix.are_rows_independent());
+    /// ```
+    pub fn are_rows_independent(&self) -> bool {
+        self.are_rows_independent_with_tolerance(DEFAULT_EPSILON)
+    }
+    /// # Are the rows linearly independent?
+    /// Uses `epsilon` as the tolerance of [`Matrix::rank_with_tolerance`]
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 2.0, 4.001], (2, 2)).unwrap();
+    /// assert!(matrix.are_rows_independent_with_tolerance(1e-4));
+    /// ```
+    pub fn are_rows_independent_with_tolerance(&self, epsilon: f64) -> bool {
+        self.rank_with_tolerance(epsilon) == self.order.0
+    }
+    /// # Approximately equal
+    /// Whether both matrices have the same order and every pair of items is within [`DEFAULT_EPSILON`]
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap();
+    /// let b = Matrix::new(vec![1.0 + 1e-12, 2.0], (1, 2)).unwrap();
+    ///
+    /// assert!(a != b);
+    /// assert!(a.approx_eq(&b));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix) -> bool {
+        self.approx_eq_with_tolerance(other, DEFAULT_EPSILON)
+    }
+    /// # Approximately equal
+    /// Whether both matrices have the same order and every pair of items is within `epsilon`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap();
+    /// let b = Matrix::new(vec![1.01, 2.0], (1, 2)).unwrap();
+    ///
+    /// assert!(!a.approx_eq(&b));
+    /// assert!(a.approx_eq_with_tolerance(&b, 0.1));
+    /// ```
+    pub fn approx_eq_with_tolerance(&self, other: &Matrix, epsilon: f64) -> bool {
+        self.order == other.order
+            && self
+                .items
+                .iter()
+                .zip(other.items.iter())
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+    /// # Permute rows
+    /// Reorder the rows so that row `i` of the result is row `perm[i]` of the matrix, counting from 0<br>
+    /// Returns [`Result`], [`Ok`] if `perm` is a permutation of `0..rows`, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (3, 2)).unwrap();
+    /// let permuted = matrix.permute_rows(&[1, 2, 0]).unwrap();
+    ///
+    /// assert!(permuted == Matrix::new(vec![3.0, 4.0, 5.0, 6.0, 1.0, 2.0], (3, 2)).unwrap());
+    /// assert_eq!(matrix.permute_rows(&[0, 0, 1]).is_err(), true);
+    /// ```
+    pub fn permute_rows(&self, perm: &[usize]) -> Result<Matrix, Errors> {
+        Matrix::validate_permutation(perm, self.order.0)?;
+        Ok(Matrix::generate(
+            |i, j| self[(perm[(i - 1) as usize] as u32 + 1, j)],
+            self.order,
+        ))
+    }
+    /// # Permute columns
+    /// Reorder the columns so that column `j` of the result is column `perm[j]` of the matrix, counting from 0<br>
+    /// Returns [`Result`], [`Ok`] if `perm` is a permutation of `0..columns`, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    /// let permuted = matrix.permute_columns(&[2, 0, 1]).unwrap();
+    ///
+    /// assert!(permuted == Matrix::new(vec![3.0, 1.0, 2.0, 6.0, 4.0, 5.0], (2, 3)).unwrap());
+    /// assert_eq!(matrix.permute_columns(&[0, 1]).is_err(), true);
+    /// ```
+    pub fn permute_columns(&self, perm: &[usize]) -> Result<Matrix, Errors> {
+        Matrix::validate_permutation(perm, self.order.1)?;
+        Ok(Matrix::generate(
+            |i, j| self[(i, perm[(j - 1) as usize] as u32 + 1)],
+            self.order,
+        ))
+    }
+    fn validate_permutation(perm: &[usize], size: u32) -> Result<(), Errors> {
+        if perm.len() != size as usize {
+            return Err(Errors::InvalidPermutation);
+        }
+        let mut seen = vec![false; perm.len()];
+        for &p in perm {
+            if p >= perm.len() || seen[p] {
+                return Err(Errors::InvalidPermutation);
+            }
+            seen[p] = true;
+        }
+        Ok(())
+    }
+    /// # Row sums
+    /// Sum of every row
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.row_sums(), vec![6.0, 15.0]);
+    /// ```
+    pub fn row_sums(&self) -> Vec<f64> {
+        (1..=self.order.0)
+            .map(|i| (1..=self.order.1).map(|j| self[(i, j)]).sum())
+            .collect()
+    }
+    /// # Column sums
+    /// Sum of every column
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.column_sums(), vec![5.0, 7.0, 9.0]);
+    /// ```
+    pub fn column_sums(&self) -> Vec<f64> {
+        (1..=self.order.1)
+            .map(|j| (1..=self.order.0).map(|i| self[(i, j)]).sum())
+            .collect()
+    }
+    /// # Row means
+    /// Average of every row
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.row_means(), vec![2.0, 5.0]);
+    /// ```
+    pub fn row_means(&self) -> Vec<f64> {
+        let columns = self.order.1 as f64;
+        self.row_sums().iter().map(|sum| sum / columns).collect()
+    }
+    /// # Column means
+    /// Average of every column, the mean of every variable when the rows are observations
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.column_means(), vec![2.5, 3.5, 4.5]);
+    /// ```
+    pub fn column_means(&self) -> Vec<f64> {
+        let rows = self.order.0 as f64;
+        self.column_sums().iter().map(|sum| sum / rows).collect()
+    }
+    /// # Covariance
+    /// Sample covariance matrix (divided by `n - 1`) treating every row as an observation and every column as a variable<br>
+    /// Use [`Matrix::population_covariance`] for the population estimator (divided by `n`)<br>
+    /// With a single observation `n - 1` is 0, so every item is NaN
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let data = Matrix::new(vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0], (3, 2)).unwrap();
+    /// // the second variable is twice the first
+    /// assert!(data.covariance() == Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2)).unwrap());
+    /// ```
+    pub fn covariance(&self) -> Matrix {
+        self.covariance_with_divisor(self.order.0 as f64 - 1.0)
+    }
+    /// # Population covariance
+    /// Population covariance matrix (divided by `n`) treating every row as an observation and every column as a variable
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let data = Matrix::new(vec![1.0, 2.0, 3.0, 6.0], (2, 2)).unwrap();
+    /// assert!(data.population_covariance() == Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2)).unwrap());
+    /// ```
+    pub fn population_covariance(&self) -> Matrix {
+        self.covariance_with_divisor(self.order.0 as f64)
+    }
+    fn covariance_with_divisor(&self, divisor: f64) -> Matrix {
+        let (rows, columns) = self.order;
+        let means = self.column_means();
+        Matrix::generate(
+            |a, b| {
+                let (mean_a, mean_b) = (means[(a - 1) as usize], means[(b - 1) as usize]);
+                (1..=rows)
+                    .map(|i| (self[(i, a)] - mean_a) * (self[(i, b)] - mean_b))
+                    .sum::<f64>()
+                    / divisor
+            },
+            (columns, columns),
+        )
+    }
+    /// # Correlation
+    /// Pearson correlation matrix treating every row as an observation and every column as a variable<br>
+    /// Items involving a variable with zero variance are NaN
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let data = Matrix::new(vec![1.0, 3.0, 2.0, 2.0, 3.0, 1.0], (3, 2)).unwrap();
+    /// // the second variable goes down as the first goes up
+    /// assert!(data.correlation() == Matrix::new(vec![1.0, -1.0, -1.0, 1.0], (2, 2)).unwrap());
+    /// ```
+    pub fn correlation(&self) -> Matrix {
+        let covariance = self.covariance();
+        Matrix::generate(
+            |a, b| {
+                let denominator = (covariance[(a, a)] * covariance[(b, b)]).sqrt();
+                if denominator == 0.0 {
+                    f64::NAN
+                } else if a == b {
+                    1.0
+                } else {
+                    covariance[(a, b)] / denominator
+                }
+            },
+            covariance.order,
+        )
+    }
+    /// # Determinant and inverse
+    /// Both the determinant and the inverse from a single LU factorization<br>
+    /// The matrix is singular if a pivot is within [`DEFAULT_EPSILON`] times its largest item of zero<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square and not singular, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![4.0, 7.0, 2.0, 6.0], (2, 2)).unwrap();
+    /// let (det, inverse) = matrix.det_and_inverse().unwrap();
+    ///
+    /// assert!((det - 10.0).abs() < 1e-12);
+    /// assert!(inverse.approx_eq(&Matrix::new(vec![0.6, -0.7, -0.2, 0.4], (2, 2)).unwrap()));
+    /// ```
+    pub fn det_and_inverse(&self) -> Result<(f64, Matrix), Errors> {
+        self.det_and_inverse_with_tolerance(DEFAULT_EPSILON)
+    }
+    /// # Determinant and inverse
+    /// Both the determinant and the inverse from a single LU factorization<br>
+    /// The matrix is singular if a pivot is within `epsilon` times its largest item of zero<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square and not singular, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let almost_singular = Matrix::new(vec![1.0, 2.0, 2.0, 4.001], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(almost_singular.det_and_inverse_with_tolerance(1e-2).is_err(), true);
+    /// assert_eq!(almost_singular.det_and_inverse_with_tolerance(1e-4).is_ok(), true);
+    /// ```
+    pub fn det_and_inverse_with_tolerance(&self, epsilon: f64) -> Result<(f64, Matrix), Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let size = self.order.0 as usize;
+        let lu = Lu::new(&self.items, size);
+        if lu.is_singular(epsilon) {
+            return Err(Errors::SingularMatrix);
+        }
+        let mut items = vec![0.0; size * size];
+        lu.inverse_into(&mut items);
+        Ok((
+            lu.determinant(),
+            Matrix {
+                items,
+                order: self.order,
+            },
+        ))
+    }
+    /// # Vandermonde matrix
+    /// Row `i` is `[1, x_i, x_i^2, ..., x_i^degree]`, the order is `(xs.len(), degree + 1)`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let vandermonde = Matrix::vandermonde(&[2.0, 3.0], 3);
+    /// assert!(vandermonde == Matrix::new(vec![1.0, 2.0, 4.0, 8.0, 1.0, 3.0, 9.0, 27.0], (2, 4)).unwrap());
+    /// ```
+    pub fn vandermonde(xs: &[f64], degree: u32) -> Matrix {
+        Matrix::generate(
+            |i, j| xs[i as usize - 1].powi(j as i32 - 1),
+            (xs.len() as u32, degree + 1),
+        )
+    }
+    /// # Polynomial fit
+    /// Least squares coefficients `[c_0, c_1, ..., c_degree]` of `c_0 + c_1 x + ... + c_degree x^degree` through the points<br>
+    /// Solves the normal equations of the Vandermonde matrix of the points centred and scaled into `[-1, 1]`, so the size of `xs` doesn't matter<br>
+    /// Returns [`Result`], [`Ok`] if `xs` and `ys` have the same length and the fit is determined, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// // y = 1 + 2x
+    /// let coefficients = Matrix::polyfit(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 5.0, 7.0], 1).unwrap();
+    ///
+    /// assert!((coefficients[0] - 1.0).abs() < 1e-10);
+    /// assert!((coefficients[1] - 2.0).abs() < 1e-10);
+    /// assert!(Matrix::polyfit(&[0.0, 1.0], &[1.0], 1).is_err());
+    /// ```
+    pub fn polyfit(xs: &[f64], ys: &[f64], degree: u32) -> Result<Vec<f64>, Errors> {
+        if xs.len() != ys.len() {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        // fit in t = (x - mean) / spread, the powers of x can be far too small or too large
+        let mean = xs.iter().sum::<f64>() / xs.len().max(1) as f64;
+        let spread = xs.iter().fold(0.0_f64, |max, x| max.max((x - mean).abs()));
+        let spread = if spread > 0.0 { spread } else { 1.0 };
+        let ts: Vec<f64> = xs.iter().map(|x| (x - mean) / spread).collect();
+
+        let vandermonde = Matrix::vandermonde(&ts, degree);
+        let transposed = vandermonde.transpose();
+        let normal = transposed.clone() * vandermonde;
+        let rhs = transposed * Matrix::new(ys.to_vec(), (ys.len() as u32, 1))?;
+
+        let lu = Lu::new(&normal.items, normal.order.0 as usize);
+        if lu.is_singular(DEFAULT_EPSILON) {
+            return Err(Errors::SingularMatrix);
+        }
+        let scaled = lu.solve(&rhs.items);
+
+        // expand c_k ((x - mean) / spread)^k back into powers of x
+        let mut coefficients = vec![0.0; scaled.len()];
+        for (k, c) in scaled.iter().enumerate() {
+            let mut binomial = 1.0;
+            for (j, coefficient) in coefficients.iter_mut().enumerate().take(k + 1) {
+                *coefficient += c * binomial * (-mean).powi((k - j) as i32) / spread.powi(k as i32);
+                binomial = binomial * (k - j) as f64 / (j + 1) as f64;
+            }
+        }
+        Ok(coefficients)
+    }
+    /// # Fold
+    /// Fold all the elements of the matrix in row by row order
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, -5.0, 3.0, 2.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.fold(0.0, |sum, x| sum + x), 1.0);
+    /// assert_eq!(matrix.fold(f64::MIN, f64::max), 3.0);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: Fn(B, f64) -> B,
+    {
+        self.items
+            .iter()
+            .fold(init, |accumulator, &x| f(accumulator, x))
+    }
+    /// # Zip iterator
+    /// Iterate over the corresponding elements of two matrices in row by row order<br>
+    /// Returns [`Result`], [`Ok`] if the matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap();
+    /// let b = Matrix::new(vec![3.0, 4.0], (1, 2)).unwrap();
+    /// let dot: f64 = a.iter_zip(&b).unwrap().map(|(x, y)| x * y).sum();
+    ///
+    /// assert_eq!(dot, 11.0);
+    /// assert_eq!(a.iter_zip(&Matrix::null_matrix((2, 1))).is_err(), true);
+    /// ```
+    pub fn iter_zip<'a>(
+        &'a self,
+        other: &'a Matrix,
+    ) -> Result<impl Iterator<Item = (f64, f64)> + 'a, Errors> {
+        if self.order != other.order {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Ok(self.items.iter().copied().zip(other.items.iter().copied()))
+    }
+    /// # Euclidean distance
+    /// Frobenius norm of the difference of two matrices<br>
+    /// Returns [`Result`], [`Ok`] if the matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap();
+    /// let b = Matrix::new(vec![4.0, 6.0], (1, 2)).unwrap();
+    ///
+    /// assert_eq!(a.euclidean_distance(&b).unwrap(), 5.0);
+    /// ```
+    pub fn euclidean_distance(&self, other: &Matrix) -> Result<f64, Errors> {
+        Ok(self
+            .iter_zip(other)?
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum::<f64>()
+            .sqrt())
+    }
+    /// # Manhattan distance
+    /// Sum of the absolute differences of the elements of two matrices<br>
+    /// Returns [`Result`], [`Ok`] if the matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap();
+    /// let b = Matrix::new(vec![4.0, 6.0], (1, 2)).unwrap();
+    ///
+    /// assert_eq!(a.manhattan_distance(&b).unwrap(), 7.0);
+    /// ```
+    pub fn manhattan_distance(&self, other: &Matrix) -> Result<f64, Errors> {
+        Ok(self.iter_zip(other)?.map(|(x, y)| (x - y).abs()).sum())
+    }
+    /// # Iterate until converged
+    /// Apply `step` repeatedly until the Frobenius distance between successive iterates is below `epsilon` or `max_iter` steps are done<br>
+    /// Returns the final iterate and the number of steps taken, a step that changes the order never converges
+    /// ```
+    /// use math_matrix::Matrix;
+    /// // x -> x / 2 + 1 converges to 2
+    /// let start = Matrix::new(vec![0.0], (1, 1)).unwrap();
+    /// let (limit, steps) = start.iterate_until_converged(|x| x.clone() / 2.0 + Matrix::new(vec![1.0], (1, 1)).unwrap(), 1e-9, 100);
+    ///
+    /// assert!((limit[(1, 1)] - 2.0).abs() < 1e-8);
+    /// assert!(steps < 100);
+    /// ```
+    pub fn iterate_until_converged<F>(
+        &self,
+        step: F,
+        epsilon: f64,
+        max_iter: usize,
+    ) -> (Matrix, usize)
+    where
+        F: Fn(&Matrix) -> Matrix,
+    {
+        let (current, count, _) = self.iterate(step, epsilon, max_iter);
+        (current, count)
+    }
+    /// Same as [`Matrix::iterate_until_converged`], also telling whether it converged
+    fn iterate<F>(&self, step: F, epsilon: f64, max_iter: usize) -> (Matrix, usize, bool)
+    where
+        F: Fn(&Matrix) -> Matrix,
+    {
+        let mut current = self.clone();
+        for count in 1..=max_iter {
+            let next = step(&current);
+            let converged = next
+                .euclidean_distance(&current)
+                .is_ok_and(|distance| distance < epsilon);
+            current = next;
+            if converged {
+                return (current, count, true);
+            }
+        }
+        (current, max_iter, false)
+    }
+    /// # Jacobi method
+    /// Solve `self * x = b` iteratively, starting from zero and updating every unknown from the previous iterate<br>
+    /// Converges for strictly diagonally dominant matrices<br>
+    /// Returns [`Result`], [`Ok`] with the solution and the number of iterations if the matrix is square, `b` is a column of the same size, no diagonal item is zero and it converges within `max_iter` iterations, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![4.0, 1.0, 2.0, 5.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![6.0, 12.0], (2, 1)).unwrap();
+    /// let (x, _) = a.solve_jacobi(&b, 1e-12, 100).unwrap();
+    ///
+    /// assert!(x.approx_eq(&Matrix::new(vec![1.0, 2.0], (2, 1)).unwrap()));
+    /// ```
+    pub fn solve_jacobi(
+        &self,
+        b: &Matrix,
+        epsilon: f64,
+        max_iter: usize,
+    ) -> Result<(Matrix, usize), Errors> {
+        let n = self.validate_iterative_system(b)?;
+        let step = |x: &Matrix| {
+            let items = (0..n)
+                .map(|i| {
+                    let off_diagonal: f64 = (0..n)
+                        .filter(|&j| j != i)
+                        .map(|j| self.items[i * n + j] * x.items[j])
+                        .sum();
+                    (b.items[i] - off_diagonal) / self.items[i * n + i]
+                })
+                .collect();
+            Matrix {
+                items,
+                order: b.order,
+            }
+        };
+        match Matrix::null_matrix(b.order).iterate(step, epsilon, max_iter) {
+            (x, count, true) => Ok((x, count)),
+            _ => Err(Errors::NotConverged),
+        }
+    }
+    /// Checks the system of an iterative solver, returns its size
+    fn validate_iterative_system(&self, b: &Matrix) -> Result<usize, Errors> {
+        if self.order.0 != self.order.1 || b.order != (self.order.0, 1) {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let n = self.order.0 as usize;
+        if (0..n).any(|i| self.items[i * n + i] == 0.0) {
+            return Err(Errors::DivisionByZero);
+        }
+        Ok(n)
+    }
+    /// # Gauss-Seidel method
+    /// Solve `self * x = b` iteratively, starting from zero and using every updated unknown right away<br>
+    /// Converges for strictly diagonally dominant and for symmetric positive definite matrices, usually faster than [`Matrix::solve_jacobi`]<br>
+    /// Returns [`Result`], [`Ok`] with the solution and the number of iterations if the matrix is square, `b` is a column of the same size, no diagonal item is zero and it converges within `max_iter` iterations, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![4.0, 1.0, 2.0, 5.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![6.0, 12.0], (2, 1)).unwrap();
+    /// let (x, _) = a.solve_gauss_seidel(&b, 1e-12, 100).unwrap();
+    ///
+    /// assert!(x.approx_eq(&Matrix::new(vec![1.0, 2.0], (2, 1)).unwrap()));
+    /// ```
+    pub fn solve_gauss_seidel(
+        &self,
+        b: &Matrix,
+        epsilon: f64,
+        max_iter: usize,
+    ) -> Result<(Matrix, usize), Errors> {
+        let n = self.validate_iterative_system(b)?;
+        let step = |x: &Matrix| {
+            let mut next = x.clone();
+            for i in 0..n {
+                let off_diagonal: f64 = (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| self.items[i * n + j] * next.items[j])
+                    .sum();
+                next.items[i] = (b.items[i] - off_diagonal) / self.items[i * n + i];
+            }
+            next
+        };
+        match Matrix::null_matrix(b.order).iterate(step, epsilon, max_iter) {
+            (x, count, true) => Ok((x, count)),
+            _ => Err(Errors::NotConverged),
+        }
+    }
+    /// # Inverse into
+    /// Write the inverse of the matrix into `out`, reusing its buffer instead of allocating a new matrix<br>
+    /// Singular means the same as in [`Matrix::is_singular`], so any matrix it accepts inverts like with [`Matrix::inverse`]<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, not singular and `out` has the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![4.0, 7.0, 2.0, 6.0], (2, 2)).unwrap();
+    /// let mut out = Matrix::null_matrix((2, 2));
+    /// matrix.inverse_into(&mut out).unwrap();
+    ///
+    /// assert!(out.approx_eq(&Matrix::new(vec![0.6, -0.7, -0.2, 0.4], (2, 2)).unwrap()));
+    /// assert_eq!(matrix.inverse_into(&mut Matrix::null_matrix((3, 3))).is_err(), true);
+    /// ```
+    pub fn inverse_into(&self, out: &mut Matrix) -> Result<(), Errors> {
+        if self.order.0 != self.order.1 || out.order != self.order {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let lu = Lu::new(&self.items, self.order.0 as usize);
+        if lu.is_singular(DEFAULT_EPSILON) {
+            return Err(Errors::SingularMatrix);
+        }
+        lu.inverse_into(&mut out.items);
+        Ok(())
+    }
+    /// # Parallel multiplication
+    /// Same product as `*`, computing the rows of the result on the rayon thread pool, requires the `rayon` feature<br>
+    /// Returns [`Result`], [`Ok`] if the number of columns of `self` is the number of rows of `rhs`, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![5.0, 6.0, 7.0, 8.0], (2, 2)).unwrap();
+    ///
+    /// assert!(a.mul_parallel(&b).unwrap() == a.clone() * b.clone());
+    /// assert_eq!(a.mul_parallel(&Matrix::null_matrix((3, 1))).is_err(), true);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn mul_parallel(&self, rhs: &Matrix) -> Result<Matrix, Errors> {
+        use rayon::prelude::*;
+        if self.order.1 != rhs.order.0 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let order = (self.order.0, rhs.order.1);
+        let mut items = vec![0.0; (order.0 * order.1) as usize];
+        if order.1 > 0 {
+            items
+                .par_chunks_mut(order.1 as usize)
+                .enumerate()
+                .for_each(|(i, row)| self.mul_row_into(rhs, i, row));
+        }
+        Ok(Matrix { items, order })
+    }
+    /// Adds row `i` of `self * rhs` into `row`, summing the products in the same order as `*`
+    #[cfg(feature = "rayon")]
+    fn mul_row_into(&self, rhs: &Matrix, i: usize, row: &mut [f64]) {
+        let inner = self.order.1 as usize;
+        for k in 0..inner {
+            let a = self.items[i * inner + k];
+            let b_row = &rhs.items[k * row.len()..(k + 1) * row.len()];
+            for (out, b) in row.iter_mut().zip(b_row) {
+                *out += a * b;
+            }
+        }
+    }
+    /// Product of matrices with compatible orders, tiled so the blocks of both operands stay in cache<br>
+    /// Every item still sums its products in increasing order of the inner index
+    fn mul_blocked(&self, rhs: &Matrix) -> Matrix {
+        const BLOCK: usize = 64;
+        let (rows, inner, columns) = (
+            self.order.0 as usize,
+            self.order.1 as usize,
+            rhs.order.1 as usize,
+        );
+        let mut items = vec![0.0; rows * columns];
+        for ii in (0..rows).step_by(BLOCK) {
+            for kk in (0..inner).step_by(BLOCK) {
+                for jj in (0..columns).step_by(BLOCK) {
+                    for i in ii..(ii + BLOCK).min(rows) {
+                        for k in kk..(kk + BLOCK).min(inner) {
+                            let a = self.items[i * inner + k];
+                            for j in jj..(jj + BLOCK).min(columns) {
+                                items[i * columns + j] += a * rhs.items[k * columns + j];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Matrix {
+            items,
+            order: (self.order.0, rhs.order.1),
+        }
+    }
+    /// # Rank one update of the inverse
+    /// With `self` being the inverse of `A`, get the inverse of `A + u v^T` by the Sherman-Morrison formula, without inverting again<br>
+    /// `u` and `v` are columns, the updated matrix is singular if `1 + v^T A^-1 u` is within [`DEFAULT_EPSILON`] of zero<br>
+    /// Returns [`Result`], [`Ok`] if the orders match and the updated matrix is not singular, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 0.0, 0.0, 4.0], (2, 2)).unwrap();
+    /// let u = Matrix::new(vec![1.0, 0.0], (2, 1)).unwrap();
+    /// let v = Matrix::new(vec![0.0, 1.0], (2, 1)).unwrap();
+    /// let updated = a.inverse().unwrap().inverse_rank1_update(&u, &v).unwrap();
+    ///
+    /// // A + u v^T = [2 1; 0 4]
+    /// assert!(updated.approx_eq(&Matrix::new(vec![2.0, 1.0, 0.0, 4.0], (2, 2)).unwrap().inverse().unwrap()));
+    /// ```
+    pub fn inverse_rank1_update(&self, u: &Matrix, v: &Matrix) -> Result<Matrix, Errors> {
+        self.inverse_rank1_update_with_tolerance(u, v, DEFAULT_EPSILON)
+    }
+    /// # Rank one update of the inverse
+    /// With `self` being the inverse of `A`, get the inverse of `A + u v^T` by the Sherman-Morrison formula, without inverting again<br>
+    /// `u` and `v` are columns, the updated matrix is singular if `1 + v^T A^-1 u` is within `epsilon` of zero<br>
+    /// Returns [`Result`], [`Ok`] if the orders match and the updated matrix is not singular, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let u = Matrix::new(vec![1.0, 0.0], (2, 1)).unwrap();
+    /// let v = Matrix::new(vec![-0.999, 0.0], (2, 1)).unwrap();
+    /// // 1 + v^T u = 0.001
+    /// let identity = Matrix::identity_matrix(2);
+    ///
+    /// assert_eq!(identity.inverse_rank1_update_with_tolerance(&u, &v, 1e-2).is_err(), true);
+    /// assert_eq!(identity.inverse_rank1_update_with_tolerance(&u, &v, 1e-4).is_ok(), true);
+    /// ```
+    pub fn inverse_rank1_update_with_tolerance(
+        &self,
+        u: &Matrix,
+        v: &Matrix,
+        epsilon: f64,
+    ) -> Result<Matrix, Errors> {
+        let n = self.order.0;
+        if self.order.1 != n || u.order != (n, 1) || v.order != (n, 1) {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        // A^-1 u and v^T A^-1
+        let inverse_u = self.clone() * u.clone();
+        let v_inverse = v.transpose() * self.clone();
+        let denominator = 1.0 + (v_inverse.clone() * u.clone()).items[0];
+        if denominator.abs() <= epsilon {
+            return Err(Errors::SingularMatrix);
+        }
+        Ok(self.clone() - inverse_u * v_inverse / denominator)
+    }
+    /// # Frobenius inner product
+    /// Sum of the products of the corresponding elements, the same as the trace of `self^T * other`<br>
+    /// Returns [`Result`], [`Ok`] if the matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![0.0, 1.0, -1.0, 2.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(a.frobenius_inner_product(&b).unwrap(), 7.0);
+    /// ```
+    pub fn frobenius_inner_product(&self, other: &Matrix) -> Result<f64, Errors> {
+        Ok(self.iter_zip(other)?.map(|(x, y)| x * y).sum())
+    }
+    /// # Permanent
+    /// Like the determinant but every term is added, calculated by Ryser's formula<br>
+    /// For a 0/1 matrix it counts the perfect matchings of the bipartite graph it describes<br>
+    /// It goes through every subset of the columns, so it takes O(2^n·n²) time and is only practical for small matrices<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square with less than 64 rows, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.permanent().unwrap(), 10.0);
+    /// ```
+    pub fn permanent(&self) -> Result<f64, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let n = self.order.0 as usize;
+        if n >= u64::BITS as usize {
+            // the subsets can't be counted in a u64
+            return Err(Errors::Overflow);
+        }
+        let mut permanent = 0.0;
+        // every subset of the columns
+        for subset in 0..1_u64 << n {
+            let product: f64 = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .filter(|&j| subset & (1 << j) != 0)
+                        .map(|j| self.items[i * n + j])
+                        .sum::<f64>()
+                })
+                .product();
+            if (n - subset.count_ones() as usize).is_multiple_of(2) {
+                permanent += product;
+            } else {
+                permanent -= product;
+            }
+        }
+        Ok(permanent)
+    }
+    /// # Similarity transform
+    /// Get `P^-1 * self * P`, the same linear map in the basis given by the columns of `P`<br>
+    /// Returns [`Result`], [`Ok`] if both matrices are square of the same size and `P` is not singular, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 0.0, 0.0, 3.0], (2, 2)).unwrap();
+    /// // swaps the basis vectors
+    /// let p = Matrix::new(vec![0.0, 1.0, 1.0, 0.0], (2, 2)).unwrap();
+    ///
+    /// assert!(a.similarity_transform(&p).unwrap() == Matrix::new(vec![3.0, 0.0, 0.0, 2.0], (2, 2)).unwrap());
+    /// ```
+    pub fn similarity_transform(&self, p: &Matrix) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 || p.order != self.order {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let (_, p_inverse) = p.det_and_inverse()?;
+        Ok(p_inverse * self.clone() * p.clone())
+    }
+    /// # Difference
+    /// Absolute difference of the corresponding elements, showing where two matrices disagree<br>
+    /// Returns [`Result`], [`Ok`] if the matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let expected = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// let actual = Matrix::new(vec![1.0, 2.5, 3.0, 3.0], (2, 2)).unwrap();
+    ///
+    /// assert!(actual.difference(&expected).unwrap() == Matrix::new(vec![0.0, 0.5, 0.0, 1.0], (2, 2)).unwrap());
+    /// ```
+    pub fn difference(&self, other: &Matrix) -> Result<Matrix, Errors> {
+        Ok(Matrix {
+            items: self.iter_zip(other)?.map(|(x, y)| (x - y).abs()).collect(),
+            order: self.order,
+        })
+    }
+    /// # Symmetrize
+    /// Get `(self + self^T) / 2`, the nearest symmetric matrix in the Frobenius norm<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 4.0, 3.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.symmetrize().unwrap() == Matrix::new(vec![1.0, 3.0, 3.0, 3.0], (2, 2)).unwrap());
+    /// ```
+    pub fn symmetrize(&self) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Ok(Matrix::generate(
+            |i, j| (self[(i, j)] + self[(j, i)]) / 2.0,
+            self.order,
+        ))
+    }
+    /// # Symmetric part
+    /// Get `(self + self^T) / 2`, which added to [`Matrix::skew_part`] gives back the matrix<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 4.0, 3.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.symmetric_part().unwrap() == Matrix::new(vec![1.0, 3.0, 3.0, 3.0], (2, 2)).unwrap());
+    /// ```
+    pub fn symmetric_part(&self) -> Result<Matrix, Errors> {
+        self.symmetrize()
+    }
+    /// # Skew symmetric part
+    /// Get `(self - self^T) / 2`, which added to [`Matrix::symmetric_part`] gives back the matrix<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 4.0, 3.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.skew_part().unwrap() == Matrix::new(vec![0.0, -1.0, 1.0, 0.0], (2, 2)).unwrap());
+    /// ```
+    pub fn skew_part(&self) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Ok(Matrix::generate(
+            |i, j| (self[(i, j)] - self[(j, i)]) / 2.0,
+            self.order,
+        ))
+    }
+    /// # Nuclear norm
+    /// Sum of the singular values, the square roots of the eigenvalues of `self^T * self`<br>
+    /// Returns [`Result`], [`Ok`] if the eigenvalues converge, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![3.0, 0.0, 0.0, 0.0, -4.0, 0.0], (2, 3)).unwrap();
+    ///
+    /// assert!((matrix.nuclear_norm().unwrap() - 7.0).abs() < 1e-10);
+    /// ```
+    pub fn nuclear_norm(&self) -> Result<f64, Errors> {
+        let (eigenvalues, _) = (self.transpose() * self.clone()).symmetric_eigen()?;
+        // tiny negative eigenvalues are rounding errors of zero singular values
+        Ok(eigenvalues.iter().map(|x| x.max(0.0).sqrt()).sum())
+    }
+    /// Eigenvalues and eigenvectors (as columns) of a symmetric matrix by cyclic Jacobi rotations<br>
+    /// Only the symmetric part of the matrix is looked at
+    fn symmetric_eigen(&self) -> Result<(Vec<f64>, Matrix), Errors> {
+        const MAX_SWEEPS: usize = 100;
+        let n = self.order.0 as usize;
+        let mut a = self.symmetrize()?.items;
+        let mut vectors = Matrix::identity_matrix(self.order.0).items;
+
+        let scale: f64 = a.iter().map(|x| x * x).sum();
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal: f64 = (0..n)
+                .flat_map(|p| (0..n).filter(move |&q| q != p).map(move |q| (p, q)))
+                .map(|(p, q)| a[p * n + q] * a[p * n + q])
+                .sum();
+            if off_diagonal <= DEFAULT_EPSILON * DEFAULT_EPSILON * scale {
+                let eigenvalues = (0..n).map(|k| a[k * n + k]).collect();
+                return Ok((
+                    eigenvalues,
+                    Matrix {
+                        items: vectors,
+                        order: self.order,
+                    },
+                ));
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p * n + q] == 0.0 {
+                        continue;
+                    }
+                    // rotation zeroing a[p][q]
+                    let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * a[p * n + q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for k in 0..n {
+                        let (kp, kq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * kp - s * kq;
+                        a[k * n + q] = s * kp + c * kq;
+                        let (kp, kq) = (vectors[k * n + p], vectors[k * n + q]);
+                        vectors[k * n + p] = c * kp - s * kq;
+                        vectors[k * n + q] = s * kp + c * kq;
+                    }
+                    for k in 0..n {
+                        let (pk, qk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * pk - s * qk;
+                        a[q * n + k] = s * pk + c * qk;
+                    }
+                }
+            }
+        }
+        Err(Errors::NotConverged)
+    }
+    /// # Singular value decomposition
+    /// Get `U`, the singular values and `V^T` so that `U * diag(s) * V^T == self`, by one sided Jacobi rotations<br>
+    /// For an `(m, n)` matrix with `k = min(m, n)`, `U` is `(m, k)` with orthonormal columns, there are `k` singular values in decreasing order and `V^T` is `(k, n)` with orthonormal rows<br>
+    /// Returns [`Result`], [`Ok`] if the rotations converge, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![3.0, 0.0, 0.0, -2.0], (2, 2)).unwrap();
+    /// let (u, s, vt) = matrix.svd().unwrap();
+    ///
+    /// assert!((s[0] - 3.0).abs() < 1e-12 && (s[1] - 2.0).abs() < 1e-12);
+    /// let sigma = Matrix::new(vec![s[0], 0.0, 0.0, s[1]], (2, 2)).unwrap();
+    /// assert!((u * sigma * vt).approx_eq(&matrix));
+    /// ```
+    pub fn svd(&self) -> Result<(Matrix, Vec<f64>, Matrix), Errors> {
+        if self.order.0 < self.order.1 {
+            // A^T = U S V^T, so A = V S U^T
+            let (u, s, vt) = self.transpose().svd()?;
+            return Ok((vt.transpose(), s, u.transpose()));
+        }
+        const MAX_SWEEPS: usize = 100;
+        let (m, n) = (self.order.0 as usize, self.order.1 as usize);
+        let mut u = self.items.clone();
+        let mut v = Matrix::identity_matrix(self.order.1).items;
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for k in 0..m {
+                        alpha += u[k * n + p] * u[k * n + p];
+                        beta += u[k * n + q] * u[k * n + q];
+                        gamma += u[k * n + p] * u[k * n + q];
+                    }
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    // rotation making columns p and q orthogonal
+                    rotated = true;
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for k in 0..m {
+                        let (kp, kq) = (u[k * n + p], u[k * n + q]);
+                        u[k * n + p] = c * kp - s * kq;
+                        u[k * n + q] = s * kp + c * kq;
+                    }
+                    for k in 0..n {
+                        let (kp, kq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * kp - s * kq;
+                        v[k * n + q] = s * kp + c * kq;
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(Errors::NotConverged);
+        }
+
+        // the singular values are the norms of the rotated columns
+        let norms: Vec<f64> = (0..n)
+            .map(|j| {
+                (0..m)
+                    .map(|k| u[k * n + j] * u[k * n + j])
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .collect();
+        let mut columns: Vec<usize> = (0..n).collect();
+        columns.sort_by(|&a, &b| norms[b].total_cmp(&norms[a]));
+        let largest = columns.first().map_or(0.0, |&j| norms[j]);
+        let negligible = f64::EPSILON * m as f64 * largest;
+
+        let singular_values: Vec<f64> = columns
+            .iter()
+            .map(|&j| {
+                if norms[j] <= negligible {
+                    0.0
+                } else {
+                    norms[j]
+                }
+            })
+            .collect();
+        let mut u_items = vec![0.0; m * n];
+        for (new, &j) in columns.iter().enumerate() {
+            if singular_values[new] > 0.0 {
+                for k in 0..m {
+                    u_items[k * n + new] = u[k * n + j] / norms[j];
+                }
+            }
+        }
+        // complete U with unit vectors orthogonal to the columns that are already there
+        for new in 0..n {
+            if singular_values[new] > 0.0 {
+                continue;
+            }
+            for basis in 0..m {
+                let mut candidate = vec![0.0; m];
+                candidate[basis] = 1.0;
+                for other in (0..n).filter(|&other| singular_values[other] > 0.0 || other < new) {
+                    let projection: f64 =
+                        (0..m).map(|k| candidate[k] * u_items[k * n + other]).sum();
+                    for (k, item) in candidate.iter_mut().enumerate() {
+                        *item -= projection * u_items[k * n + other];
+                    }
+                }
+                let norm = candidate.iter().map(|x| x * x).sum::<f64>().sqrt();
+                if norm > 0.5 {
+                    for (k, item) in candidate.iter().enumerate() {
+                        u_items[k * n + new] = item / norm;
+                    }
+                    break;
+                }
+            }
+        }
+        let vt = Matrix::generate(
+            |i, j| v[(j as usize - 1) * n + columns[i as usize - 1]],
+            (self.order.1, self.order.1),
+        );
+
+        Ok((
+            Matrix {
+                items: u_items,
+                order: self.order,
+            },
+            singular_values,
+            vt,
+        ))
+    }
+    /// # LaTeX
+    /// Write the matrix as a LaTeX `pmatrix`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.5, -3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.to_latex(), "\\begin{pmatrix}\n1 & 2.5 \\\\\n-3 & 4\n\\end{pmatrix}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.latex_with(|x| x.to_string())
+    }
+    /// # LaTeX with precision
+    /// Write the matrix as a LaTeX `pmatrix` with every element rounded to `decimals` decimal places
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0 / 3.0], (1, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.to_latex_precision(2), "\\begin{pmatrix}\n1.00 & 0.67\n\\end{pmatrix}");
+    /// ```
+    pub fn to_latex_precision(&self, decimals: usize) -> String {
+        self.latex_with(|x| format!("{:.*}", decimals, x))
+    }
+    /// `pmatrix` with every element written by `format`
+    fn latex_with<F>(&self, format: F) -> String
+    where
+        F: Fn(f64) -> String,
+    {
+        let rows: Vec<String> = self
+            .items
+            .chunks(self.order.1.max(1) as usize)
+            .map(|row| {
+                row.iter()
+                    .map(|&x| format(x))
+                    .collect::<Vec<String>>()
+                    .join(" & ")
+            })
+            .collect();
+        format!(
+            "\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}",
+            rows.join(" \\\\\n")
+        )
+    }
+    /// # Markdown
+    /// Write the matrix as a Markdown table, the header holds the column indices
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.5, -3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.to_markdown(), "| 1 | 2 |\n|---|---|\n| 1 | 2.5 |\n| -3 | 4 |");
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let columns = self.order.1 as usize;
+        let mut lines = vec![
+            format!(
+                "|{}",
+                (1..=columns)
+                    .map(|j| format!(" {} |", j))
+                    .collect::<String>()
+            ),
+            format!("|{}", "---|".repeat(columns)),
+        ];
+        lines.extend(self.items.chunks(columns.max(1)).map(|row| {
+            format!(
+                "|{}",
+                row.iter().map(|x| format!(" {} |", x)).collect::<String>()
+            )
+        }));
+        lines.join("\n")
+    }
+    /// # Deviatoric part
+    /// Get `self - (tr(self) / n) I`, the trace free part of a square matrix<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![3.0, 1.0, 2.0, 5.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.deviatoric().unwrap() == Matrix::new(vec![-1.0, 1.0, 2.0, 1.0], (2, 2)).unwrap());
+    /// ```
+    pub fn deviatoric(&self) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let mean = self.trace_rectangular() / self.order.0 as f64;
+        Ok(Matrix::generate(
+            |i, j| {
+                if i == j {
+                    self[(i, j)] - mean
+                } else {
+                    self[(i, j)]
+                }
+            },
+            self.order,
+        ))
+    }
+    /// # Sylvester equation
+    /// Solve `A X + X B = C` for `X`, as the linear system of the Kronecker sum `A ⊗ I + I ⊗ B^T` on the items of `X`<br>
+    /// `A` is `(m, m)`, `B` is `(n, n)` and `C` is `(m, n)` like `X`<br>
+    /// The solution is not unique if a pivot of the system is within [`DEFAULT_EPSILON`] times its largest item of zero<br>
+    /// Returns [`Result`], [`Ok`] if the orders are compatible and the solution is unique, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 0.0, 0.0, 2.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![3.0], (1, 1)).unwrap();
+    /// let c = Matrix::new(vec![8.0, 10.0], (2, 1)).unwrap();
+    /// let x = Matrix::solve_sylvester(&a, &b, &c).unwrap();
+    ///
+    /// assert!(x.approx_eq(&Matrix::new(vec![2.0, 2.0], (2, 1)).unwrap()));
+    /// ```
+    pub fn solve_sylvester(a: &Matrix, b: &Matrix, c: &Matrix) -> Result<Matrix, Errors> {
+        Matrix::solve_sylvester_with_tolerance(a, b, c, DEFAULT_EPSILON)
+    }
+    /// # Sylvester equation
+    /// Solve `A X + X B = C` for `X`, as the linear system of the Kronecker sum `A ⊗ I + I ⊗ B^T` on the items of `X`<br>
+    /// The solution is not unique if a pivot of the system is within `epsilon` times its largest item of zero<br>
+    /// Returns [`Result`], [`Ok`] if the orders are compatible and the solution is unique, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// // the first eigenvalues of A and -B almost meet
+    /// let a = Matrix::new(vec![1.0, 0.0, 0.0, 3.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![-0.999], (1, 1)).unwrap();
+    /// let c = Matrix::new(vec![1.0, 1.0], (2, 1)).unwrap();
+    ///
+    /// assert_eq!(Matrix::solve_sylvester_with_tolerance(&a, &b, &c, 1e-2).is_err(), true);
+    /// assert_eq!(Matrix::solve_sylvester_with_tolerance(&a, &b, &c, 1e-4).is_ok(), true);
+    /// ```
+    pub fn solve_sylvester_with_tolerance(
+        a: &Matrix,
+        b: &Matrix,
+        c: &Matrix,
+        epsilon: f64,
+    ) -> Result<Matrix, Errors> {
+        let (m, n) = c.order;
+        if a.order != (m, m) || b.order != (n, n) {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let (m, n) = (m as usize, n as usize);
+        let size = m * n;
+        // X[i][j] is unknown i * n + j
+        let mut system = vec![0.0; size * size];
+        for i in 0..m {
+            for j in 0..n {
+                let row = (i * n + j) * size;
+                for k in 0..m {
+                    system[row + k * n + j] += a.items[i * m + k];
+                }
+                for l in 0..n {
+                    system[row + i * n + l] += b.items[l * n + j];
+                }
+            }
+        }
+        let lu = Lu::new(&system, size);
+        if lu.is_singular(epsilon) {
+            return Err(Errors::SingularMatrix);
+        }
+        Ok(Matrix {
+            items: lu.solve(&c.items),
+            order: c.order,
+        })
+    }
+    /// # Diagonalize
+    /// Get `P` and the diagonal `D` so that `self == P * D * P^-1`, the columns of `P` are unit eigenvectors and the diagonal of `D` holds their eigenvalues<br>
+    /// Symmetric matrices use Jacobi rotations and get an orthogonal `P`, other matrices use shifted QR iterations, so only real eigenvalues are supported<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square and has a basis of real eigenvectors, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![2.0, 1.0, 1.0, 2.0], (2, 2)).unwrap();
+    /// let (p, d) = matrix.diagonalize().unwrap();
+    ///
+    /// assert!((p.clone() * d * p.inverse().unwrap()).approx_eq(&matrix));
+    /// // a shear has a single eigenvector
+    /// let shear = Matrix::new(vec![1.0, 1.0, 0.0, 1.0], (2, 2)).unwrap();
+    /// assert_eq!(shear.diagonalize().is_err(), true);
+    /// ```
+    pub fn diagonalize(&self) -> Result<(Matrix, Matrix), Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let n = self.order.0 as usize;
+        let diagonal = |values: &[f64]| {
+            Matrix::generate(
+                |i, j| if i == j { values[i as usize - 1] } else { 0.0 },
+                self.order,
+            )
+        };
+        if *self == self.transpose() {
+            let (values, vectors) = self.symmetric_eigen()?;
+            return Ok((vectors, diagonal(&values)));
+        }
+
+        let mut values = self.real_eigenvalues().ok_or(Errors::NotDiagonalizable)?;
+        values.sort_by(f64::total_cmp);
+        let scale = self.items.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+        let tolerance = 1e-8 * scale.max(1.0);
+
+        // one null space per distinct eigenvalue
+        let mut vectors: Vec<Vec<f64>> = vec![];
+        let mut eigenvalues = vec![];
+        let mut start = 0;
+        while start < n {
+            let mut end = start + 1;
+            while end < n && values[end] - values[start] <= tolerance {
+                end += 1;
+            }
+            let value = values[start..end].iter().sum::<f64>() / (end - start) as f64;
+            let shifted = self.clone() - Matrix::identity_matrix(self.order.0) * value;
+            let basis = shifted.null_space(tolerance);
+            if basis.len() < end - start {
+                return Err(Errors::NotDiagonalizable);
+            }
+            eigenvalues.extend(std::iter::repeat_n(value, basis.len()));
+            vectors.extend(basis);
+            start = end;
+        }
+        if vectors.len() != n {
+            return Err(Errors::NotDiagonalizable);
+        }
+        let p = Matrix::generate(|i, j| vectors[j as usize - 1][i as usize - 1], self.order);
+        Ok((p, diagonal(&eigenvalues)))
+    }
+    /// Eigenvalues by QR iterations with Wilkinson shifts, [`None`] if they don't all converge to real values
+    fn real_eigenvalues(&self) -> Option<Vec<f64>> {
+        let mut m = self.order.0 as usize;
+        let mut a = self.items.clone();
+        let scale = self.items.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let mut values = vec![];
+        let mut iterations = 0;
+        while m > 0 {
+            // deflate once the last row is negligible left of the diagonal
+            if (0..m - 1).all(|j| a[(m - 1) * m + j].abs() <= f64::EPSILON * scale) {
+                values.push(a[(m - 1) * m + m - 1]);
+                let mut deflated = Vec::with_capacity((m - 1) * (m - 1));
+                for i in 0..m - 1 {
+                    deflated.extend_from_slice(&a[i * m..i * m + m - 1]);
+                }
+                a = deflated;
+                m -= 1;
+                continue;
+            }
+            iterations += 1;
+            if iterations > 100 * self.order.0 as usize {
+                return None;
+            }
+
+            // the eigenvalue of the trailing 2x2 block closer to the last item
+            let (p, q) = (a[(m - 2) * m + m - 2], a[(m - 2) * m + m - 1]);
+            let (r, s) = (a[(m - 1) * m + m - 2], a[(m - 1) * m + m - 1]);
+            let half_trace = (p + s) / 2.0;
+            let discriminant = half_trace * half_trace - (p * s - q * r);
+            let shift = if discriminant >= 0.0 {
+                let root = discriminant.sqrt();
+                if (half_trace + root - s).abs() < (half_trace - root - s).abs() {
+                    half_trace + root
+                } else {
+                    half_trace - root
+                }
+            } else {
+                s
+            };
+            for k in 0..m {
+                a[k * m + k] -= shift;
+            }
+
+            // R = H_(m-2) ... H_0 (A - shift I), then R H_0 ... H_(m-2) + shift I
+            let mut reflectors = vec![];
+            for k in 0..m - 1 {
+                let norm = (k..m)
+                    .map(|i| a[i * m + k] * a[i * m + k])
+                    .sum::<f64>()
+                    .sqrt();
+                let alpha = if a[k * m + k] > 0.0 { -norm } else { norm };
+                let mut v: Vec<f64> = (k..m).map(|i| a[i * m + k]).collect();
+                v[0] -= alpha;
+                let vv: f64 = v.iter().map(|x| x * x).sum();
+                if vv == 0.0 {
+                    continue;
+                }
+                for j in 0..m {
+                    let dot: f64 = (k..m).map(|i| v[i - k] * a[i * m + j]).sum();
+                    for i in k..m {
+                        a[i * m + j] -= 2.0 * dot / vv * v[i - k];
+                    }
+                }
+                reflectors.push((k, v, vv));
+            }
+            for (k, v, vv) in reflectors {
+                for i in 0..m {
+                    let dot: f64 = (k..m).map(|j| a[i * m + j] * v[j - k]).sum();
+                    for j in k..m {
+                        a[i * m + j] -= 2.0 * dot / vv * v[j - k];
+                    }
+                }
+            }
+            for k in 0..m {
+                a[k * m + k] += shift;
+            }
+        }
+        Some(values)
+    }
+    /// Unit vectors spanning the null space, from the reduced row echelon form with pivots above `epsilon`
+    fn null_space(&self, epsilon: f64) -> Vec<Vec<f64>> {
+        let (rows, columns) = (self.order.0 as usize, self.order.1 as usize);
+        let mut items = self.items.clone();
+        let mut pivot_columns = vec![];
+        for column in 0..columns {
+            let rank = pivot_columns.len();
+            if rank == rows {
+                break;
+            }
+            let pivot_row = (rank..rows)
+                .max_by(|&a, &b| {
+                    items[a * columns + column]
+                        .abs()
+                        .total_cmp(&items[b * columns + column].abs())
+                })
+                .expect("Impossible");
+            let pivot = items[pivot_row * columns + column];
+            if pivot.abs() <= epsilon {
+                continue;
+            }
+            for c in 0..columns {
+                items.swap(rank * columns + c, pivot_row * columns + c);
+                items[rank * columns + c] /= pivot;
+            }
+            for r in (0..rows).filter(|&r| r != rank) {
+                let factor = items[r * columns + column];
+                for c in column..columns {
+                    items[r * columns + c] -= factor * items[rank * columns + c];
+                }
+            }
+            pivot_columns.push(column);
+        }
+
+        (0..columns)
+            .filter(|column| !pivot_columns.contains(column))
+            .map(|free| {
+                let mut vector = vec![0.0; columns];
+                vector[free] = 1.0;
+                for (row, &pivot_column) in pivot_columns.iter().enumerate() {
+                    vector[pivot_column] = -items[row * columns + free];
+                }
+                let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+                vector.iter().map(|x| x / norm).collect()
+            })
+            .collect()
+    }
+    /// # Checked solve
+    /// Solve `self * x = b` with LU decomposition, refusing when the reciprocal condition number `1 / (‖A‖₁ ‖A^-1‖₁)` is below `rcond_threshold`<br>
+    /// A matrix can pass the singularity check and still lose most of the digits of the solution, the reciprocal condition number tells how many are left<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, `b` has as many rows, the matrix is not singular and well conditioned enough, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![2.0, 1.0, 1.0, 3.0], (2, 2)).unwrap();
+    /// let b = Matrix::new(vec![3.0, 5.0], (2, 1)).unwrap();
+    ///
+    /// assert!(a.solve_checked(&b, 1e-8).unwrap().approx_eq(&Matrix::new(vec![0.8, 1.4], (2, 1)).unwrap()));
+    /// ```
+    pub fn solve_checked(&self, b: &Matrix, rcond_threshold: f64) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 || b.order.0 != self.order.0 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let n = self.order.0 as usize;
+        let lu = Lu::new(&self.items, n);
+        if lu.is_singular(DEFAULT_EPSILON) {
+            return Err(Errors::SingularMatrix);
+        }
+        let mut inverse = vec![0.0; n * n];
+        lu.inverse_into(&mut inverse);
+        let norm_1 = |items: &[f64]| {
+            (0..n)
+                .map(|j| (0..n).map(|i| items[i * n + j].abs()).sum::<f64>())
+                .fold(0.0, f64::max)
+        };
+        let rcond = 1.0 / (norm_1(&self.items) * norm_1(&inverse));
+        if rcond.is_nan() || rcond < rcond_threshold {
+            return Err(Errors::IllConditioned);
+        }
+
+        let columns = b.order.1 as usize;
+        let mut items = vec![0.0; n * columns];
+        for j in 0..columns {
+            let column: Vec<f64> = (0..n).map(|i| b.items[i * columns + j]).collect();
+            for (i, value) in lu.solve(&column).into_iter().enumerate() {
+                items[i * columns + j] = value;
+            }
+        }
+        Ok(Matrix {
+            items,
+            order: b.order,
+        })
+    }
+    /// # Flatten to a row
+    /// Get a `(1, rows * columns)` row vector of all the elements in row by row order
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.flatten_row() == Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (1, 4)).unwrap());
+    /// ```
+    pub fn flatten_row(&self) -> Matrix {
+        Matrix {
+            items: self.items.clone(),
+            order: (1, self.order.0 * self.order.1),
+        }
+    }
+    /// # Flatten to a column
+    /// Get a `(rows * columns, 1)` column vector of all the elements in row by row order
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.flatten_column() == Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (4, 1)).unwrap());
+    /// ```
+    pub fn flatten_column(&self) -> Matrix {
+        Matrix {
+            items: self.items.clone(),
+            order: (self.order.0 * self.order.1, 1),
+        }
+    }
+    /// # Trace of a power
+    /// Sum of the diagonal of `self^k`, forming `self^(k - 1)` by repeated multiplication so it is O(k·n³)<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 1.0, 1.0, 0.0], (2, 2)).unwrap();
+    ///
+    /// // the traces of the Fibonacci matrix are the Lucas numbers
+    /// assert_eq!(matrix.trace_of_power(0).unwrap(), 2.0);
+    /// assert_eq!(matrix.trace_of_power(5).unwrap(), 11.0);
+    /// ```
+    pub fn trace_of_power(&self, k: u32) -> Result<f64, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::TraceExistsOnlyForSquareMatrices);
+        }
+        if k == 0 {
+            return Ok(self.order.0 as f64);
+        }
+        let mut power = Matrix::identity_matrix(self.order.0);
+        for _ in 1..k {
+            power = power.mul_blocked(self);
+        }
+        // only the diagonal of the last product is needed
+        power.frobenius_inner_product(&self.transpose())
+    }
+    /// # Is the matrix row stochastic?
+    /// Every element is nonnegative and every row sums to 1 within `epsilon`, like a Markov chain transition matrix
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![0.9, 0.1, 0.5, 0.5], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.is_row_stochastic(1e-12));
+    /// assert!(!matrix.is_column_stochastic(1e-12));
+    /// ```
+    pub fn is_row_stochastic(&self, epsilon: f64) -> bool {
+        self.items.iter().all(|&x| x >= 0.0)
+            && (1..=self.order.0).all(|i| {
+                let sum: f64 = (1..=self.order.1).map(|j| self[(i, j)]).sum();
+                (sum - 1.0).abs() <= epsilon
+            })
+    }
+    /// # Is the matrix column stochastic?
+    /// Every element is nonnegative and every column sums to 1 within `epsilon`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![0.9, 0.5, 0.1, 0.5], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.is_column_stochastic(1e-12));
+    /// assert!(!matrix.is_row_stochastic(1e-12));
+    /// ```
+    pub fn is_column_stochastic(&self, epsilon: f64) -> bool {
+        self.transpose().is_row_stochastic(epsilon)
+    }
+    /// # Steady state
+    /// Stationary distribution of a row stochastic transition matrix as a row vector, by power iteration from the uniform distribution<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, row stochastic and the iteration converges within `max_iter` steps, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let chain = Matrix::new(vec![0.5, 0.5, 0.25, 0.75], (2, 2)).unwrap();
+    /// let steady = chain.steady_state(1e-12, 1000).unwrap();
+    ///
+    /// assert!(steady.approx_eq(&Matrix::new(vec![1.0 / 3.0, 2.0 / 3.0], (1, 2)).unwrap()));
+    /// ```
+    pub fn steady_state(&self, epsilon: f64, max_iter: usize) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        if !self.is_row_stochastic(DEFAULT_EPSILON) {
+            return Err(Errors::NotStochastic);
+        }
+        let n = self.order.0;
+        let uniform = Matrix::generate(|_, _| 1.0 / n as f64, (1, n));
+        match uniform.iterate(
+            |distribution| distribution.mul_blocked(self),
+            epsilon,
+            max_iter,
+        ) {
+            (distribution, _, true) => Ok(distribution),
+            _ => Err(Errors::NotConverged),
+        }
+    }
+    /// # Clone into a buffer
+    /// Copy the matrix into `dst`, reusing the allocation of `dst` and only growing it when it is too small<br>
+    /// The order of `dst` becomes the order of the matrix
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// let mut dst = Matrix::null_matrix((1, 4));
+    /// matrix.clone_into_buffer(&mut dst);
+    ///
+    /// assert!(dst == matrix);
+    /// ```
+    pub fn clone_into_buffer(&self, dst: &mut Matrix) {
+        dst.items.clear();
+        dst.items.extend_from_slice(&self.items);
+        dst.order = self.order;
+    }
+    /// # Same shape
+    /// Whether both matrices have the same order, whatever their elements are
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap();
+    ///
+    /// assert!(a.same_shape(&Matrix::null_matrix((1, 2))));
+    /// assert!(!a.same_shape(&a.transpose()));
+    /// ```
+    pub fn same_shape(&self, other: &Matrix) -> bool {
+        self.order == other.order
+    }
+    /// # Same data
+    /// Whether both matrices hold the same elements in row by row order, whatever their orders are<br>
+    /// `==` needs both the same shape and the same data
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let row = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (1, 4)).unwrap();
+    /// let square = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(row.same_data(&square));
+    /// assert!(row != square);
+    /// ```
+    pub fn same_data(&self, other: &Matrix) -> bool {
+        self.items == other.items
+    }
+    /// # Logarithm
+    /// Principal logarithm from the first `terms` terms of the series `log(I + X) = X - X²/2 + X³/3 - ...` with `X = self - I`<br>
+    /// The series only converges when the eigenvalues of `X` are smaller than 1 in absolute value, and slowly when they are close to it<br>
+    /// It is only used when the Frobenius norm of `X` is smaller than 1, which is enough for that<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square and the norm of `X` is smaller than 1, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.5, 0.0, 0.0, 1.0], (2, 2)).unwrap();
+    /// let log = matrix.log(100).unwrap();
+    ///
+    /// assert!(log.approx_eq(&Matrix::new(vec![1.5_f64.ln(), 0.0, 0.0, 0.0], (2, 2)).unwrap()));
+    /// ```
+    pub fn log(&self, terms: usize) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let x = self.clone() - Matrix::identity_matrix(self.order.0);
+        if x.frobenius_inner_product(&x)? >= 1.0 {
+            // the series may diverge
+            return Err(Errors::NotConverged);
+        }
+        let mut power = x.clone();
+        let mut log = Matrix::null_matrix(self.order);
+        for k in 1..=terms {
+            let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+            log += power.clone() * (sign / k as f64);
+            power = power.mul_blocked(&x);
+        }
+        Ok(log)
+    }
+    /// # Sign function
+    /// Matrix sign function by the Newton iteration `X = (X + X^-1) / 2` starting from the matrix, until successive iterates are within `epsilon`<br>
+    /// It maps eigenvalues in the right half plane to 1 and in the left half plane to -1, so its trace counts them<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, no iterate is singular and it converges within `max_iter` iterations, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![3.0, 0.0, 0.0, -0.5], (2, 2)).unwrap();
+    /// let sign = matrix.sign_function(100, 1e-12).unwrap();
+    ///
+    /// assert!(sign.approx_eq(&Matrix::new(vec![1.0, 0.0, 0.0, -1.0], (2, 2)).unwrap()));
+    /// ```
+    pub fn sign_function(&self, max_iter: usize, epsilon: f64) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let mut x = self.clone();
+        for _ in 0..max_iter {
+            let (_, inverse) = x.det_and_inverse()?;
+            let next = (x.clone() + inverse) / 2.0;
+            let converged = next.euclidean_distance(&x)? < epsilon;
+            x = next;
+            if converged {
+                return Ok(x);
+            }
+        }
+        Err(Errors::NotConverged)
+    }
+    /// # Masked
+    /// Keep the elements where the mask is non zero and put 0 everywhere else<br>
+    /// Returns [`Result`], [`Ok`] if the matrix and the mask have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// let mask = Matrix::new(vec![1.0, 0.0, 0.0, 1.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.masked(&mask).unwrap() == Matrix::new(vec![1.0, 0.0, 0.0, 4.0], (2, 2)).unwrap());
+    /// ```
+    pub fn masked(&self, mask: &Matrix) -> Result<Matrix, Errors> {
+        Ok(Matrix {
+            items: self
+                .iter_zip(mask)?
+                .map(|(x, keep)| if keep != 0.0 { x } else { 0.0 })
+                .collect(),
+            order: self.order,
+        })
+    }
+    /// # With rows
+    /// Copy of the matrix with `new_rows` rows, dropping the last rows or appending rows filled with `fill`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.with_rows(3, 0.0) == Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 0.0, 0.0], (3, 2)).unwrap());
+    /// assert!(matrix.with_rows(1, 0.0) == Matrix::new(vec![1.0, 2.0], (1, 2)).unwrap());
+    /// ```
+    pub fn with_rows(&self, new_rows: u32, fill: f64) -> Matrix {
+        let len = (new_rows * self.order.1) as usize;
+        let mut items: Vec<f64> = self.items.iter().copied().take(len).collect();
+        items.resize(len, fill);
+        Matrix {
+            items,
+            order: (new_rows, self.order.1),
+        }
+    }
+    /// # With columns
+    /// Copy of the matrix with `new_cols` columns, dropping the last columns or appending columns filled with `fill`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.with_columns(3, 0.0) == Matrix::new(vec![1.0, 2.0, 0.0, 3.0, 4.0, 0.0], (2, 3)).unwrap());
+    /// assert!(matrix.with_columns(1, 0.0) == Matrix::new(vec![1.0, 3.0], (2, 1)).unwrap());
+    /// ```
+    pub fn with_columns(&self, new_cols: u32, fill: f64) -> Matrix {
+        let columns = self.order.1;
+        Matrix::generate_0based(
+            |i, j| {
+                if j < columns {
+                    self.items[(i * columns + j) as usize]
+                } else {
+                    fill
+                }
+            },
+            (self.order.0, new_cols),
+        )
+    }
+    /// # Contains
+    /// Whether any element is within `epsilon` of `value`
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.contains(3.0, 1e-10));
+    /// assert!(!matrix.contains(5.0, 1e-10));
+    /// ```
+    pub fn contains(&self, value: f64, epsilon: f64) -> bool {
+        self.position_of(value, epsilon).is_some()
+    }
+    /// # Position of
+    /// First position, row by row, of an element within `epsilon` of `value`<br>
+    /// Returns [`Option`], [`Some`] with the 1-based `(i, j)` if there is one, [`None`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.position_of(3.0, 1e-10), Some((2, 1)));
+    /// assert_eq!(matrix.position_of(5.0, 1e-10), None);
+    /// ```
+    pub fn position_of(&self, value: f64, epsilon: f64) -> Option<(u32, u32)> {
+        let index = self
+            .items
+            .iter()
+            .position(|item| (item - value).abs() <= epsilon)? as u32;
+        Some((index / self.order.1 + 1, index % self.order.1 + 1))
+    }
+    /// # Principal component analysis
+    /// The `n_components` eigenvectors of the covariance with the largest eigenvalues, treating every row as an observation and every column as a variable<br>
+    /// The components are the columns of the matrix, in decreasing order of their eigenvalue, which is the variance along them<br>
+    /// Returns [`Result`], [`Ok`] with the components and their eigenvalues if there are at least 2 observations and `n_components` is at most the number of columns, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// // all the variance is along the second variable
+    /// let data = Matrix::new(vec![1.0, 0.0, 1.0, 2.0, 1.0, 4.0], (3, 2)).unwrap();
+    /// let (components, variances) = data.pca(1).unwrap();
+    ///
+    /// assert_eq!(components.order, (2, 1));
+    /// assert!((components[(2, 1)].abs() - 1.0).abs() < 1e-10);
+    /// assert!((variances[0] - 4.0).abs() < 1e-10);
+    /// ```
+    pub fn pca(&self, n_components: u32) -> Result<(Matrix, Vec<f64>), Errors> {
+        if self.order.0 < 2 {
+            // the sample covariance needs 2 observations
+            return Err(Errors::InappropriateNumberOfItems);
+        }
+        if n_components > self.order.1 {
+            return Err(Errors::IndexOutOfRange);
+        }
+        let (eigenvalues, eigenvectors) = self.covariance().symmetric_eigen()?;
+        let mut indices: Vec<usize> = (0..eigenvalues.len()).collect();
+        indices.sort_by(|&a, &b| eigenvalues[b].total_cmp(&eigenvalues[a]));
+        indices.truncate(n_components as usize);
+
+        let components = Matrix::generate_0based(
+            |i, j| eigenvectors[(i + 1, indices[j as usize] as u32 + 1)],
+            (self.order.1, n_components),
+        );
+        let variances = indices.iter().map(|&k| eigenvalues[k]).collect();
+        Ok((components, variances))
+    }
+    /// # Push a row
+    /// Append a row at the bottom of the matrix<br>
+    /// A `(0, 0)` matrix takes the length of the row as its number of columns<br>
+    /// Returns [`Result`], [`Ok`] if the row has one item per column, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.push_row(vec![5.0, 6.0]).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (3, 2)).unwrap());
+    /// assert_eq!(matrix.push_row(vec![7.0]).is_err(), true);
+    /// ```
+    pub fn push_row(&mut self, values: Vec<f64>) -> Result<(), Errors> {
+        if self.order == (0, 0) {
+            self.order.1 = values.len() as u32;
+        }
+        if values.len() != self.order.1 as usize {
+            return Err(Errors::InappropriateNumberOfItems);
+        }
+        self.items.extend(values);
+        self.order.0 += 1;
+        Ok(())
+    }
+    /// # Push a column
+    /// Append a column on the right of the matrix<br>
+    /// A `(0, 0)` matrix takes the length of the column as its number of rows<br>
+    /// Returns [`Result`], [`Ok`] if the column has one item per row, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.push_column(vec![5.0, 6.0]).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0], (2, 3)).unwrap());
+    /// assert_eq!(matrix.push_column(vec![7.0]).is_err(), true);
+    /// ```
+    pub fn push_column(&mut self, values: Vec<f64>) -> Result<(), Errors> {
+        if self.order == (0, 0) {
+            self.order.0 = values.len() as u32;
+        }
+        if values.len() != self.order.0 as usize {
+            return Err(Errors::InappropriateNumberOfItems);
+        }
+        let columns = self.order.1 as usize;
+        // insert from the last row so the earlier positions do not move
+        for (i, value) in values.into_iter().enumerate().rev() {
+            self.items.insert((i + 1) * columns, value);
+        }
+        self.order.1 += 1;
+        Ok(())
+    }
+    /// # Sort rows by a column
+    /// Reorder the rows by the values in column `column`, in ascending or descending order<br>
+    /// The sort is stable, rows with equal values keep their order in both directions, values compare with [`f64::total_cmp`]<br>
+    /// Returns [`Result`], [`Ok`] if the column exists, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 3.0, 2.0, 1.0, 3.0, 2.0], (3, 2)).unwrap();
+    ///
+    /// assert!(matrix.sort_rows_by_column(2, true).unwrap() == Matrix::new(vec![2.0, 1.0, 3.0, 2.0, 1.0, 3.0], (3, 2)).unwrap());
+    /// assert_eq!(matrix.sort_rows_by_column(3, true).is_err(), true);
+    /// ```
+    pub fn sort_rows_by_column(&self, column: u32, ascending: bool) -> Result<Matrix, Errors> {
+        let keys = self.get_column(column)?;
+        let mut perm: Vec<usize> = (0..keys.len()).collect();
+        if ascending {
+            perm.sort_by(|&a, &b| keys[a].total_cmp(&keys[b]));
+        } else {
+            perm.sort_by(|&a, &b| keys[b].total_cmp(&keys[a]));
+        }
+        self.permute_rows(&perm)
+    }
+    /// # Unique rows
+    /// Keep only the first occurrence of rows that are equal within `epsilon` item by item, in their original order<br>
+    /// Rows without columns are all equal, so only one of them is kept
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0], (3, 2)).unwrap();
+    ///
+    /// assert!(matrix.unique_rows(1e-10) == Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap());
+    /// ```
+    pub fn unique_rows(&self, epsilon: f64) -> Matrix {
+        let columns = self.order.1 as usize;
+        if columns == 0 {
+            return Matrix::null_matrix((self.order.0.min(1), 0));
+        }
+        let mut unique = Matrix::null_matrix((0, self.order.1));
+        for row in self.items.chunks(columns) {
+            let seen = unique
+                .items
+                .chunks(columns)
+                .any(|kept| kept.iter().zip(row).all(|(a, b)| (a - b).abs() <= epsilon));
+            if !seen {
+                unique.items.extend_from_slice(row);
+                unique.order.0 += 1;
+            }
+        }
+        unique
+    }
+    /// # Weighted row combination
+    /// The single row `weights[0] * row_1 + weights[1] * row_2 + ...`, same as the row matrix of the weights times the matrix<br>
+    /// Returns [`Result`], [`Ok`] if there is one weight per row, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.weighted_row_combination(&[2.0, -1.0]).unwrap() == Matrix::row_matrix(vec![-1.0, 0.0]));
+    /// assert_eq!(matrix.weighted_row_combination(&[1.0]).is_err(), true);
+    /// ```
+    pub fn weighted_row_combination(&self, weights: &[f64]) -> Result<Matrix, Errors> {
+        if weights.len() != self.order.0 as usize {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let mut items = vec![0.0; self.order.1 as usize];
+        for (row, &weight) in self.items.chunks(self.order.1.max(1) as usize).zip(weights) {
+            for (item, x) in items.iter_mut().zip(row) {
+                *item += weight * x;
+            }
+        }
+        Ok(Matrix {
+            items,
+            order: (1, self.order.1),
+        })
+    }
+    /// # Determinant of a block triangular matrix
+    /// `det(A) * det(C)` for a matrix `[[A, B], [0, C]]` whose block `A` is the first `split` rows and columns<br>
+    /// Much cheaper than [`Matrix::det`] when the structure is known, the zero block must be zero within [`DEFAULT_EPSILON`]<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, `split` is inside it and the block under `A` is zero, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![2.0, 1.0, 7.0, 0.0, 3.0, 4.0, 0.0, 0.0, 5.0], (3, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.determinant_block_triangular(2).unwrap(), 30.0);
+    /// assert_eq!(matrix.transpose().determinant_block_triangular(2).is_err(), true);
+    /// ```
+    pub fn determinant_block_triangular(&self, split: u32) -> Result<f64, Errors> {
+        let n = self.order.0;
+        if self.order.1 != n {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        if split == 0 || split >= n {
+            return Err(Errors::IndexOutOfRange);
+        }
+        for i in split + 1..=n {
+            for j in 1..=split {
+                if self[(i, j)].abs() > DEFAULT_EPSILON {
+                    return Err(Errors::NotBlockTriangular);
+                }
+            }
+        }
+        let a = Matrix::generate(|i, j| self[(i, j)], (split, split));
+        let c = Matrix::generate(|i, j| self[(i + split, j + split)], (n - split, n - split));
+        Ok(a.det()? * c.det()?)
+    }
+    /// # Multiply by a vector
+    /// `self * v` with `v` as a column, without wrapping it in a [`Matrix`]<br>
+    /// Returns [`Result`], [`Ok`] if `v` has one item per column, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+    ///
+    /// assert_eq!(matrix.mul_vec(&[1.0, 0.0, -1.0]).unwrap(), vec![-2.0, -2.0]);
+    /// assert_eq!(matrix.mul_vec(&[1.0, 0.0]).is_err(), true);
+    /// ```
+    pub fn mul_vec(&self, v: &[f64]) -> Result<Vec<f64>, Errors> {
+        if v.len() != self.order.1 as usize {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        let columns = v.len();
+        Ok((0..self.order.0 as usize)
+            .map(|i| {
+                self.items[i * columns..(i + 1) * columns]
+                    .iter()
+                    .zip(v)
+                    .map(|(a, b)| a * b)
+                    .sum()
+            })
+            .collect())
+    }
+    /// # Element-wise power
+    /// Raise every element to the power at the same position in `exponents`, with [`f64::powf`]<br>
+    /// Returns [`Result`], [`Ok`] if the matrix and the exponents have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![2.0, 3.0, 4.0, 9.0], (2, 2)).unwrap();
+    /// let exponents = Matrix::new(vec![3.0, 2.0, -1.0, 0.5], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.hadamard_pow(&exponents).unwrap() == Matrix::new(vec![8.0, 9.0, 0.25, 3.0], (2, 2)).unwrap());
+    /// ```
+    pub fn hadamard_pow(&self, exponents: &Matrix) -> Result<Matrix, Errors> {
+        Ok(Matrix {
+            items: self
+                .iter_zip(exponents)?
+                .map(|(x, exponent)| x.powf(exponent))
+                .collect(),
+            order: self.order,
+        })
+    }
+    /// # View
+    /// Borrow the matrix in a [`MatrixView`] caching its determinant, rank and norm
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::identity_matrix(3);
+    /// let view = matrix.view();
+    ///
+    /// assert_eq!(view.determinant().unwrap(), 1.0);
+    /// assert_eq!(view.determinant().unwrap(), 1.0);
+    /// ```
+    pub fn view(&self) -> MatrixView<'_> {
+        MatrixView::new(self)
+    }
+    /// # Replace
+    /// Copy of the matrix with every element within `epsilon` of `old` replaced by `new`<br>
+    /// NaN is never within `epsilon` of anything, so it cannot be replaced this way
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let matrix = Matrix::new(vec![1.0, -999.0, 3.0, -999.0], (2, 2)).unwrap();
+    ///
+    /// assert!(matrix.replace(-999.0, 0.0, 1e-10) == Matrix::new(vec![1.0, 0.0, 3.0, 0.0], (2, 2)).unwrap());
+    /// ```
+    pub fn replace(&self, old: f64, new: f64, epsilon: f64) -> Matrix {
+        Matrix {
+            items: self
+                .items
+                .iter()
+                .map(|&x| if (x - old).abs() <= epsilon { new } else { x })
+                .collect(),
+            order: self.order,
+        }
+    }
+    /// # Checked addition
+    /// Same as `+` without panicking<br>
+    /// Returns [`Result`], [`Ok`] if both matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(a.checked_add(&a).unwrap() == Matrix::new(vec![2.0, 4.0, 6.0, 8.0], (2, 2)).unwrap());
+    /// assert_eq!(a.checked_add(&Matrix::identity_matrix(3)).is_err(), true);
+    /// ```
+    pub fn checked_add(&self, rhs: &Matrix) -> Result<Matrix, Errors> {
+        Ok(Matrix {
+            items: self.iter_zip(rhs)?.map(|(a, b)| a + b).collect(),
+            order: self.order,
+        })
+    }
+    /// # Checked subtraction
+    /// Same as `-` without panicking<br>
+    /// Returns [`Result`], [`Ok`] if both matrices have the same order, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(a.checked_sub(&a).unwrap() == Matrix::null_matrix((2, 2)));
+    /// assert_eq!(a.checked_sub(&Matrix::identity_matrix(3)).is_err(), true);
+    /// ```
+    pub fn checked_sub(&self, rhs: &Matrix) -> Result<Matrix, Errors> {
+        Ok(Matrix {
+            items: self.iter_zip(rhs)?.map(|(a, b)| a - b).collect(),
+            order: self.order,
+        })
+    }
+    /// # Checked multiplication
+    /// Same as `*` without panicking<br>
+    /// Returns [`Result`], [`Ok`] if the number of columns of `self` is the number of rows of `rhs`, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert!(a.checked_mul(&Matrix::identity_matrix(2)).unwrap() == a);
+    /// assert_eq!(a.checked_mul(&Matrix::identity_matrix(3)).is_err(), true);
+    /// ```
+    pub fn checked_mul(&self, rhs: &Matrix) -> Result<Matrix, Errors> {
+        if self.order.1 != rhs.order.0 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        Ok(self.mul_blocked(rhs))
+    }
+    /// # Swap rows
+    /// Swap rows `i` and `j` in place<br>
+    /// Returns [`Result`], [`Ok`] if both rows exist, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.swap_rows(1, 2).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![3.0, 4.0, 1.0, 2.0], (2, 2)).unwrap());
+    /// ```
+    pub fn swap_rows(&mut self, i: u32, j: u32) -> Result<(), Errors> {
+        if i == 0 || i > self.order.0 || j == 0 || j > self.order.0 {
+            return Err(Errors::IndexOutOfRange);
+        }
+        let columns = self.order.1 as usize;
+        for c in 0..columns {
+            self.items.swap(
+                (i - 1) as usize * columns + c,
+                (j - 1) as usize * columns + c,
+            );
+        }
+        Ok(())
+    }
+    /// # Scale a row
+    /// Multiply row `i` by `factor` in place<br>
+    /// Returns [`Result`], [`Ok`] if the row exists, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.scale_row(2, 0.5).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![1.0, 2.0, 1.5, 2.0], (2, 2)).unwrap());
+    /// ```
+    pub fn scale_row(&mut self, i: u32, factor: f64) -> Result<(), Errors> {
+        if i == 0 || i > self.order.0 {
+            return Err(Errors::IndexOutOfRange);
+        }
+        for j in 1..=self.order.1 {
+            self[(i, j)] *= factor;
+        }
+        Ok(())
+    }
+    /// # Add a scaled row
+    /// Add `factor` times row `source` to row `target` in place, use [`Matrix::scale_row`] to scale a row by itself<br>
+    /// Returns [`Result`], [`Ok`] if both rows exist and are different, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    /// matrix.add_scaled_row(2, 1, -3.0).unwrap();
+    ///
+    /// assert!(matrix == Matrix::new(vec![1.0, 2.0, 0.0, -2.0], (2, 2)).unwrap());
+    /// ```
+    pub fn add_scaled_row(&mut self, target: u32, source: u32, factor: f64) -> Result<(), Errors> {
+        if target == 0
+            || target > self.order.0
+            || source == 0
+            || source > self.order.0
+            || target == source
+        {
+            return Err(Errors::IndexOutOfRange);
+        }
+        for j in 1..=self.order.1 {
+            let value = self[(source, j)];
+            self[(target, j)] += factor * value;
+        }
+        Ok(())
+    }
+    /// # Apply a row operation
+    /// Apply any [`RowOp`] in place, so sequences of them can be recorded and replayed<br>
+    /// Returns [`Result`], [`Ok`] if the rows of the operation exist and those of [`RowOp::AddScaled`] are different, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::{Matrix, RowOp};
+    /// let mut matrix = Matrix::new(vec![0.0, 2.0, 1.0, 4.0], (2, 2)).unwrap();
+    /// for op in [RowOp::Swap(1, 2), RowOp::Scale(2, 0.5), RowOp::AddScaled { target: 1, source: 2, factor: -4.0 }] {
+    ///     matrix.apply_row_op(op).unwrap();
+    /// }
+    ///
+    /// assert!(matrix == Matrix::identity_matrix(2));
+    /// ```
+    pub fn apply_row_op(&mut self, op: RowOp) -> Result<(), Errors> {
+        match op {
+            RowOp::Swap(i, j) => self.swap_rows(i, j),
+            RowOp::Scale(i, factor) => self.scale_row(i, factor),
+            RowOp::AddScaled {
+                target,
+                source,
+                factor,
+            } => self.add_scaled_row(target, source, factor),
+        }
+    }
+    /// # Power
+    /// Raise a square matrix to an integer power by repeated squaring, with O(log exponent) multiplications<br>
+    /// The power 0 is the identity matrix<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    /// ```
+    /// use math_matrix::Matrix;
+    /// let fibonacci = Matrix::new(vec![1.0, 1.0, 1.0, 0.0], (2, 2)).unwrap();
+    ///
+    /// assert!(fibonacci.pow(10).unwrap() == Matrix::new(vec![89.0, 55.0, 55.0, 34.0], (2, 2)).unwrap());
+    /// assert!(fibonacci.pow(0).unwrap() == Matrix::identity_matrix(2));
+    /// ```
+    pub fn pow(&self, exponent: u32) -> Result<Matrix, Errors> {
+        if self.order.0 != self.order.1 {
+            return Err(Errors::IncorrectOrdersForOperation);
+        }
+        if exponent == 0 {
+            return Ok(Matrix::identity_matrix(self.order.0));
+        }
+        let mut result: Option<Matrix> = None;
+        let mut square = self.clone();
+        let mut exponent = exponent;
+        loop {
+            if exponent & 1 == 1 {
+                result = Some(match result {
+                    Some(result) => result.mul_blocked(&square),
+                    None => square.clone(),
+                });
+            }
+            exponent >>= 1;
+            if exponent == 0 {
+                return Ok(result.expect("Impossible"));
+            }
+            square = square.mul_blocked(&square);
+        }
+    }
+    /// # Apply a row operation tracking the determinant
+    /// Same as [`Matrix::apply_row_op`], returning the factor the determinant gets multiplied by<br>
+    /// `-1` for a swap of two different rows, the factor for a scale and `1` for adding a scaled row<br>
+    /// Panics where [`Matrix::apply_row_op`] errors, if the rows of the operation don't exist or a row is added to itself, like the operators do
+    /// ```
+    /// use math_matrix::{Matrix, RowOp};
+    /// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.apply_row_op_tracked(RowOp::Swap(1, 2)), -1.0);
+    /// assert_eq!(matrix.apply_row_op_tracked(RowOp::Scale(1, 3.0)), 3.0);
+    /// assert_eq!(matrix.det().unwrap(), 6.0);
+    /// ```
+    pub fn apply_row_op_tracked(&mut self, op: RowOp) -> f64 {
+        self.apply_row_op(op).expect("Error");
+        match op {
+            RowOp::Swap(i, j) if i != j => -1.0,
+            RowOp::Swap(..) => 1.0,
+            RowOp::Scale(_, factor) => factor,
+            RowOp::AddScaled { .. } => 1.0,
+        }
+    }
+}
+/// # Linear combination
+/// Sum of `coefficient * matrix` for every term<br>
+/// Returns [`Result`], [`Ok`] if there is at least one term and all matrices have the same order, [`Err`] otherwise
+/// ```
+/// use math_matrix::{linear_combination, Matrix};
+/// let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+/// let b = Matrix::identity_matrix(2);
+/// let combination = linear_combination(&[(2.0, a), (-1.0, b)]).unwrap();
+///
+/// assert!(combination == Matrix::new(vec![1.0, 4.0, 6.0, 7.0], (2, 2)).unwrap());
+/// assert_eq!(linear_combination(&[]).is_err(), true);
+/// ```
+pub fn linear_combination(terms: &[(f64, Matrix)]) -> Result<Matrix, Errors> {
+    let order = match terms.first() {
+        Some((_, matrix)) => matrix.order,
+        None => return Err(Errors::InappropriateNumberOfItems),
+    };
+    if terms.iter().any(|(_, matrix)| matrix.order != order) {
+        return Err(Errors::IncorrectOrdersForOperation);
+    }
+    let mut items = vec![0.0; (order.0 * order.1) as usize];
+    for (coefficient, matrix) in terms {
+        for (item, x) in items.iter_mut().zip(matrix.items.iter()) {
+            *item += coefficient * x;
+        }
+    }
+    Ok(Matrix { items, order })
+}
+impl Add for Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Error")
+    }
+}
+impl AddAssign for Matrix {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.checked_add(&rhs).expect("Error");
+    }
+}
+impl Sub for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Error")
+    }
+}
+impl SubAssign for Matrix {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.checked_sub(&rhs).expect("Error");
+    }
+}
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("Error")
+    }
+}
+impl Mul<f64> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Matrix::generate(|i, j| self.get(i, j).expect("Impossible") * rhs, self.order)
+    }
+}
+
+impl MulAssign for Matrix {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.checked_mul(&rhs).expect("Error");
+    }
+}
+impl MulAssign<f64> for Matrix {
+    fn mul_assign(&mut self, rhs: f64) {
+        for item in self.items.iter_mut() {
+            *item *= rhs;
+        }
+    }
+}
+impl Div<f64> for Matrix {
+    type Output = Matrix;
+    fn div(self, rhs: f64) -> Self::Output {
+        Matrix::generate(|i, j| self.get(i, j).expect("Impossible") / rhs, self.order)
+    }
+}
+impl DivAssign<f64> for Matrix {
     fn div_assign(&mut self, rhs: f64) {
-        *self = Matrix::generate(|i, j| self.get(i, j).expect("Impossible") / rhs, self.order)
+        for item in self.items.iter_mut() {
+            *item /= rhs;
+        }
+    }
+}
+impl Index<(u32, u32)> for Matrix {
+    type Output = f64;
+    fn index(&self, (i, j): (u32, u32)) -> &Self::Output {
+        &self.items[((i - 1) * self.order.1 + (j - 1)) as usize]
+    }
+}
+impl IndexMut<(u32, u32)> for Matrix {
+    fn index_mut(&mut self, (i, j): (u32, u32)) -> &mut Self::Output {
+        &mut self.items[((i - 1) * self.order.1 + (j - 1)) as usize]
+    }
+}
+/// Index the items directly in row by row order, bypassing the `(i, j)` coordinates<br>
+/// Unlike the coordinates, the flat index starts from 0
+/// ```
+/// use math_matrix::Matrix;
+/// let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).unwrap();
+/// assert_eq!(matrix[4], matrix[(2, 2)]);
+///
+/// matrix[5] = 9.0;
+/// assert_eq!(matrix[(2, 3)], 9.0);
+/// ```
+impl Index<usize> for Matrix {
+    type Output = f64;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.items[index]
+    }
+}
+impl IndexMut<usize> for Matrix {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.items[index]
+    }
+}
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // whole numbers are written without a trailing `.0`, every column is right aligned
+        // to its own widest item
+        let columns = self.order.1.max(1) as usize;
+        let items: Vec<String> = self.items.iter().map(|item| item.to_string()).collect();
+        let mut widths = vec![0; columns];
+        for (i, item) in items.iter().enumerate() {
+            widths[i % columns] = widths[i % columns].max(item.chars().count());
+        }
+
+        let rows: Vec<String> = items
+            .chunks(columns)
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(item, &width)| format!("{:>width$}", item))
+                    .collect::<Vec<String>>()
+                    .join("  ")
+            })
+            .collect();
+        f.write_str(&rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn addition() {
+        use crate::Matrix;
+        let a = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (3, 2)).expect("Impossible");
+        let b = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+
+        let sum = a + b;
+        assert_eq!(sum.order, (3, 2));
+        assert_eq!(sum.items, vec![49.0, 89.0, 67.0, 998.0, 18.0, 105.0]);
+    }
+
+    #[test]
+    fn subtraction() {
+        use crate::Matrix;
+        let a = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (3, 2)).expect("Impossible");
+        let b = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+
+        let diff = a - b;
+        assert_eq!(diff.order, (3, 2));
+        assert_eq!(diff.items, vec![41.0, -85.0, 63.0, 800.0, -6.0, 17.0]);
+    }
+
+    #[test]
+    fn multiplication() {
+        use crate::Matrix;
+        let a = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+
+        let scalar_mul = a.clone() * 5.0;
+        assert_eq!(scalar_mul.order, (3, 2));
+        assert_eq!(
+            scalar_mul.items,
+            vec![20.0, 435.0, 10.0, 495.0, 60.0, 220.0]
+        );
+
+        let b = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (2, 3)).expect("Impossible");
+
+        let matrix_mul = a * b;
+        assert_eq!(matrix_mul.order, (3, 3));
+        assert_eq!(
+            matrix_mul.items,
+            vec![78393.0, 530.0, 5567.0, 89091.0, 598.0, 6169.0, 40096.0, 288.0, 3464.0]
+        );
+
+        let matrix =
+            Matrix::new(vec![1.0, 6.0, 4.0, 2.0, 5.0, 7.0, 4.0, 2.0, 9.0], (3, 3)).unwrap();
+        let inverse = matrix.inverse().unwrap();
+
+        // Rounding because 0.999999991 is just 1
+        assert!((matrix * inverse).round() == Matrix::identity_matrix(3));
+    }
+
+    #[test]
+    fn transpose_mut() {
+        use crate::Matrix;
+        let mut square = Matrix::generate(|i, j| (i * 10 + j) as f64, (4, 4));
+        let expected = square.transpose();
+        square.transpose_mut();
+        assert!(square == expected);
+
+        let mut rectangular = Matrix::generate(|i, j| (i * 10 + j) as f64, (2, 5));
+        let expected = rectangular.transpose();
+        rectangular.transpose_mut();
+        assert_eq!(rectangular.order, (5, 2));
+        assert!(rectangular == expected);
+    }
+
+    #[test]
+    fn as_array() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).expect("Impossible");
+        let array: [[f64; 2]; 2] = matrix.as_array().expect("Impossible");
+        assert_eq!(array, [[1.0, 2.0], [3.0, 4.0]]);
+
+        assert!(matrix.as_array::<3, 3>().is_err());
+        assert!(matrix.as_array::<1, 4>().is_err());
+    }
+
+    #[test]
+    fn to_bool_mask() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![3.0, -1.0, 0.0, -7.5, 2.0, 9.0], (2, 3)).expect("Impossible");
+        let mask = matrix.to_bool_mask(|x| x > 0.0);
+        assert_eq!(
+            mask,
+            vec![vec![true, false, false], vec![false, true, true]]
+        );
+    }
+
+    #[test]
+    fn householder() {
+        use crate::{Errors, Matrix};
+        let v = Matrix::column_matrix(vec![3.0, 1.0, 2.0]);
+        let h = Matrix::householder(&v).expect("Impossible");
+
+        for i in 1..=3 {
+            for j in 1..=3 {
+                assert!((h[(i, j)] - h[(j, i)]).abs() < 1e-12);
+            }
+        }
+        let product = h.clone() * h.transpose();
+        for (a, b) in product.items.iter().zip(Matrix::identity_matrix(3).items) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        let reflected = h * v.clone();
+        for (a, b) in reflected.items.iter().zip(v.items) {
+            assert!((a + b).abs() < 1e-12);
+        }
+
+        let zero = Matrix::column_matrix(vec![0.0, 0.0]);
+        assert!(matches!(
+            Matrix::householder(&zero),
+            Err(Errors::ZeroVector)
+        ));
+        let row = Matrix::row_matrix(vec![1.0, 2.0]);
+        assert!(matches!(
+            Matrix::householder(&row),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn givens() {
+        use crate::Matrix;
+        let g = Matrix::givens(4, 2, 4, 0.3).expect("Impossible");
+        let product = g.clone() * g.transpose();
+        for (a, b) in product.items.iter().zip(Matrix::identity_matrix(4).items) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let theta = std::f64::consts::FRAC_PI_4;
+        let rotation = Matrix::givens(2, 1, 2, theta).expect("Impossible");
+        let rotated = rotation * Matrix::column_matrix(vec![1.0, 0.0]);
+        assert!((rotated[(1, 1)] - theta.cos()).abs() < 1e-12);
+        assert!((rotated[(2, 1)] - theta.sin()).abs() < 1e-12);
+
+        assert!(Matrix::givens(3, 0, 2, theta).is_err());
+        assert!(Matrix::givens(3, 2, 2, theta).is_err());
+    }
+
+    #[test]
+    fn diagonal_product() {
+        use crate::Matrix;
+        let lower = Matrix::new(
+            vec![
+                3.0, 0.0, 0.0, 0.0, 1.0, -2.0, 0.0, 0.0, 4.0, 6.0, 5.0, 0.0, 9.0, 8.0, 7.0, 0.5,
+            ],
+            (4, 4),
+        )
+        .expect("Impossible");
+        let det = lower.to_determinant().expect("Impossible").value();
+        assert_eq!(lower.diagonal_product().expect("Impossible"), -15.0);
+        assert_eq!(lower.diagonal_product().expect("Impossible"), det);
+
+        assert!(Matrix::null_matrix((2, 3)).diagonal_product().is_err());
+    }
+
+    #[test]
+    fn flat_index() {
+        use crate::Matrix;
+        let mut matrix = Matrix::generate(|i, j| (i * 10 + j) as f64, (3, 4));
+        assert_eq!(matrix[0], matrix[(1, 1)]);
+        assert_eq!(matrix[6], matrix[(2, 3)]);
+        assert_eq!(matrix[11], matrix[(3, 4)]);
+
+        matrix[7] = -1.0;
+        assert_eq!(matrix[(2, 4)], -1.0);
+    }
+
+    #[test]
+    fn div_elementwise() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![10.0, 6.0, -4.0, 7.0, 0.0, 1.0], (2, 3)).expect("Impossible");
+        let b = Matrix::new(vec![2.0, 3.0, 4.0, 2.0, 5.0, 0.25], (2, 3)).expect("Impossible");
+
+        let quotient = a.div_elementwise(&b).expect("Impossible");
+        assert_eq!(quotient.items, vec![5.0, 2.0, -1.0, 3.5, 0.0, 4.0]);
+        assert!(a.div_elementwise_checked(&b).expect("Impossible") == quotient);
+
+        let mismatched = Matrix::null_matrix((3, 2));
+        assert!(matches!(
+            a.div_elementwise(&mismatched),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn div_elementwise_by_zero() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![1.0, -1.0, 0.0], (1, 3)).expect("Impossible");
+        let zeros = Matrix::null_matrix((1, 3));
+
+        let quotient = a.div_elementwise(&zeros).expect("Impossible");
+        assert_eq!(quotient[(1, 1)], f64::INFINITY);
+        assert_eq!(quotient[(1, 2)], f64::NEG_INFINITY);
+        assert!(quotient[(1, 3)].is_nan());
+
+        assert!(matches!(
+            a.div_elementwise_checked(&zeros),
+            Err(Errors::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn linear_combination() {
+        use crate::{linear_combination, Errors, Matrix};
+        let a = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (3, 2)).expect("Impossible");
+        let b = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+
+        let combination =
+            linear_combination(&[(2.0, a.clone()), (-1.0, b.clone())]).expect("Impossible");
+        assert!(combination == a * 2.0 - b.clone());
+
+        assert!(matches!(
+            linear_combination(&[(1.0, b), (1.0, Matrix::null_matrix((2, 3)))]),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        assert!(matches!(
+            linear_combination(&[]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+    }
+
+    #[test]
+    fn is_singular() {
+        use crate::Matrix;
+        let singular = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], (3, 3))
+            .expect("Impossible");
+        assert!(singular.is_singular().expect("Impossible"));
+
+        // needs a row swap before the first pivot
+        let invertible = Matrix::new(vec![0.0, 2.0, 1.0, 1.0, 0.0, 3.0, 4.0, 1.0, 0.0], (3, 3))
+            .expect("Impossible");
+        assert!(!invertible.is_singular().expect("Impossible"));
+
+        assert!(Matrix::null_matrix((2, 3)).is_singular().is_err());
+
+        // the tolerance follows the size of the items
+        let tiny = Matrix::identity_matrix(3) * 1e-11;
+        assert!(!tiny.is_singular().expect("Impossible"));
+        assert_eq!(
+            tiny.to_determinant()
+                .expect("Impossible")
+                .sign()
+                .expect("Impossible"),
+            1.0
+        );
+        assert!((singular * 1e-12).is_singular().expect("Impossible"));
+        assert!(Matrix::null_matrix((2, 2))
+            .is_singular()
+            .expect("Impossible"));
+    }
+
+    #[test]
+    fn validate() {
+        use crate::{Errors, Matrix};
+        assert!(Matrix::validate(12, (3, 4)).is_ok());
+        assert!(Matrix::validate(0, (0, 5)).is_ok());
+        assert!(matches!(
+            Matrix::validate(11, (3, 4)),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+    }
+
+    #[test]
+    fn eigenvalue_sanity() {
+        use crate::Matrix;
+        // eigenvalues: 2, 5
+        let matrix = Matrix::new(vec![4.0, 1.0, 2.0, 3.0], (2, 2)).expect("Impossible");
+        assert_eq!(matrix.eigenvalue_sanity().expect("Impossible"), (7.0, 10.0));
+
+        assert!(Matrix::null_matrix((3, 2)).eigenvalue_sanity().is_err());
+    }
+
+    #[test]
+    fn split_at_row() {
+        use crate::{Errors, Matrix};
+        let matrix =
+            Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], (4, 2)).expect("Impossible");
+        let (top, bottom) = matrix.split_at_row(2).expect("Impossible");
+        assert_eq!(top.order, (2, 2));
+        assert_eq!(top.items, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(bottom.order, (2, 2));
+        assert_eq!(bottom.items, vec![5.0, 6.0, 7.0, 8.0]);
+
+        assert!(matches!(
+            matrix.split_at_row(5),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn split_at_column() {
+        use crate::{Errors, Matrix};
+        let matrix =
+            Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], (2, 4)).expect("Impossible");
+        let (left, right) = matrix.split_at_column(1).expect("Impossible");
+        assert_eq!(left.order, (2, 1));
+        assert_eq!(left.items, vec![1.0, 5.0]);
+        assert_eq!(right.order, (2, 3));
+        assert_eq!(right.items, vec![2.0, 3.0, 4.0, 6.0, 7.0, 8.0]);
+
+        assert!(matches!(
+            matrix.split_at_column(5),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn diagonal_k() {
+        use crate::Matrix;
+        // 11  12  13  14
+        // 21  22  23  24
+        // 31  32  33  34
+        let matrix = Matrix::generate(|i, j| (i * 10 + j) as f64, (3, 4));
+        assert_eq!(matrix.diagonal_k(0), vec![11.0, 22.0, 33.0]);
+        assert_eq!(matrix.diagonal_k(1), vec![12.0, 23.0, 34.0]);
+        assert_eq!(matrix.diagonal_k(-1), vec![21.0, 32.0]);
+        assert_eq!(matrix.diagonal_k(3), vec![14.0]);
+        assert!(matrix.diagonal_k(4).is_empty());
+        assert!(matrix.diagonal_k(-3).is_empty());
+    }
+
+    #[test]
+    fn from_diagonals() {
+        use crate::{Errors, Matrix};
+        let tridiagonal = Matrix::from_diagonals(
+            vec![
+                (-1, vec![1.0, 1.0, 1.0]),
+                (0, vec![-2.0, -2.0, -2.0, -2.0]),
+                (1, vec![3.0, 3.0, 3.0]),
+            ],
+            (4, 4),
+        )
+        .expect("Impossible");
+        assert_eq!(
+            tridiagonal.items,
+            vec![
+                -2.0, 3.0, 0.0, 0.0, 1.0, -2.0, 3.0, 0.0, 0.0, 1.0, -2.0, 3.0, 0.0, 0.0, 1.0, -2.0
+            ]
+        );
+        assert_eq!(tridiagonal.diagonal_k(-1), vec![1.0, 1.0, 1.0]);
+
+        assert!(matches!(
+            Matrix::from_diagonals(vec![(0, vec![1.0, 2.0])], (4, 4)),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+        assert!(matches!(
+            Matrix::from_diagonals(vec![(4, vec![])], (4, 4)),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn solve_tridiagonal() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::from_diagonals(
+            vec![
+                (-1, vec![1.0, 2.0, -1.0, 1.0]),
+                (0, vec![4.0, 5.0, 6.0, 4.0, 3.0]),
+                (1, vec![-1.0, 1.0, 2.0, 1.0]),
+            ],
+            (5, 5),
+        )
+        .expect("Impossible");
+        let b = Matrix::column_matrix(vec![3.0, 8.0, 1.0, -2.0, 7.0]);
+
+        let x = a.solve_tridiagonal(&b).expect("Impossible");
+        let expected = a.inverse().expect("Impossible") * b.clone();
+        for (p, q) in x.items.iter().zip(expected.items.iter()) {
+            assert!((p - q).abs() < 1e-10);
+        }
+        let residual = a * x - b;
+        assert!(residual.items.iter().all(|r| r.abs() < 1e-10));
+
+        let dense = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0], (3, 3))
+            .expect("Impossible");
+        assert!(matches!(
+            dense.solve_tridiagonal(&Matrix::column_matrix(vec![1.0, 1.0, 1.0])),
+            Err(Errors::NotTridiagonal)
+        ));
+    }
+
+    #[test]
+    fn linear_independence() {
+        use crate::Matrix;
+        // the third column is a copy of the first
+        let duplicated = Matrix::new(
+            vec![1.0, 4.0, 1.0, 2.0, 5.0, 2.0, 3.0, 6.0, 3.0, 7.0, 1.0, 7.0],
+            (4, 3),
+        )
+        .expect("Impossible");
+        assert_eq!(duplicated.rank(), 2);
+        assert!(!duplicated.are_columns_independent());
+        assert!(!duplicated.are_rows_independent());
+
+        let independent =
+            Matrix::new(vec![1.0, 4.0, 2.0, 5.0, 3.0, 7.0], (3, 2)).expect("Impossible");
+        assert!(independent.are_columns_independent());
+        assert!(independent.transpose().are_rows_independent());
+
+        // the tolerance follows the size of the items
+        assert_eq!((Matrix::identity_matrix(3) * 1e-11).rank(), 3);
+        assert_eq!((duplicated * 1e-12).rank(), 2);
+        assert_eq!(Matrix::null_matrix((2, 3)).rank(), 0);
+    }
+
+    #[test]
+    fn default_epsilon() {
+        use crate::{Matrix, DEFAULT_EPSILON};
+        let a = Matrix::new(vec![1.0, -2.0, 3.5, 0.0], (2, 2)).expect("Impossible");
+        let b = Matrix::generate(|i, j| a[(i, j)] + DEFAULT_EPSILON / 2.0, (2, 2));
+        assert!(a != b);
+        assert!(a.approx_eq(&b));
+        assert!(a.approx_eq_with_tolerance(&b, DEFAULT_EPSILON));
+
+        let far = Matrix::generate(|i, j| a[(i, j)] + DEFAULT_EPSILON * 10.0, (2, 2));
+        assert!(!a.approx_eq(&far));
+        assert!(a.approx_eq_with_tolerance(&far, 1e-8));
+
+        // same items, different order
+        let reshaped = Matrix::row_matrix(a.items.clone());
+        assert!(!a.approx_eq(&reshaped));
+    }
+
+    #[test]
+    fn permute_rows() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (3, 2)).expect("Impossible");
+        let reversed = matrix.permute_rows(&[2, 1, 0]).expect("Impossible");
+        assert_eq!(reversed.order, (3, 2));
+        assert_eq!(reversed.items, vec![5.0, 6.0, 3.0, 4.0, 1.0, 2.0]);
+
+        assert!(matches!(
+            matrix.permute_rows(&[0, 1, 3]),
+            Err(Errors::InvalidPermutation)
+        ));
+        assert!(matches!(
+            matrix.permute_rows(&[1, 1, 0]),
+            Err(Errors::InvalidPermutation)
+        ));
+    }
+
+    #[test]
+    fn permute_columns() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (3, 2)).expect("Impossible");
+        let swapped = matrix.permute_columns(&[1, 0]).expect("Impossible");
+        assert_eq!(swapped.items, vec![2.0, 1.0, 4.0, 3.0, 6.0, 5.0]);
+        assert!(matrix.permute_columns(&[0, 1, 2]).is_err());
+    }
+
+    #[test]
+    fn covariance() {
+        use crate::Matrix;
+        // x: 2, 4, 6, 8  y: 1, 3, 2, 6
+        let data =
+            Matrix::new(vec![2.0, 1.0, 4.0, 3.0, 6.0, 2.0, 8.0, 6.0], (4, 2)).expect("Impossible");
+        // means: 5, 3
+        // sum of products of deviations: xx 20, yy 14, xy 14
+        let sample = data.covariance();
+        assert_eq!(sample.order, (2, 2));
+        assert!(sample.approx_eq(
+            &Matrix::new(vec![20.0 / 3.0, 14.0 / 3.0, 14.0 / 3.0, 14.0 / 3.0], (2, 2))
+                .expect("Impossible")
+        ));
+
+        let population = data.population_covariance();
+        assert!(population
+            .approx_eq(&Matrix::new(vec![5.0, 3.5, 3.5, 3.5], (2, 2)).expect("Impossible")));
+
+        // a single observation has no sample covariance
+        let single = Matrix::row_matrix(vec![1.0, 2.0]);
+        assert!(single.covariance().items.iter().all(|x| x.is_nan()));
+        assert!(single.population_covariance() == Matrix::null_matrix((2, 2)));
+    }
+
+    #[test]
+    fn correlation() {
+        use crate::Matrix;
+        let data = Matrix::new(
+            vec![2.0, 1.0, 7.0, 4.0, 3.0, 7.0, 6.0, 2.0, 7.0, 8.0, 6.0, 7.0],
+            (4, 3),
+        )
+        .expect("Impossible");
+        let correlation = data.correlation();
+        assert_eq!(correlation.order, (3, 3));
+        assert_eq!(correlation[(1, 1)], 1.0);
+        assert_eq!(correlation[(2, 2)], 1.0);
+        assert_eq!(correlation[(1, 2)], correlation[(2, 1)]);
+        // 14 / sqrt(20 * 14)
+        assert!((correlation[(1, 2)] - (14.0_f64 / 20.0).sqrt()).abs() < 1e-12);
+
+        // the third column is constant
+        assert!(correlation[(3, 3)].is_nan());
+        assert!(correlation[(1, 3)].is_nan());
+    }
+
+    #[test]
+    fn det_and_inverse() {
+        use crate::{Errors, Matrix};
+        // needs row swaps during the factorization
+        let matrix = Matrix::new(vec![0.0, 2.0, 1.0, 1.0, 0.0, 3.0, 4.0, 1.0, 0.0], (3, 3))
+            .expect("Impossible");
+        let (det, inverse) = matrix.det_and_inverse().expect("Impossible");
+        assert!((det - matrix.to_determinant().expect("Impossible").value()).abs() < 1e-10);
+        assert!(inverse.approx_eq(&matrix.inverse().expect("Impossible")));
+        assert!((matrix * inverse).approx_eq(&Matrix::identity_matrix(3)));
+
+        let singular = Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            singular.det_and_inverse(),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).det_and_inverse(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+
+        // uniformly tiny matrices are not singular
+        let tiny = Matrix::identity_matrix(3) * 1e-11;
+        let (det, inverse) = tiny.det_and_inverse().expect("Impossible");
+        assert!((det / 1e-33 - 1.0).abs() < 1e-12);
+        assert!(inverse.approx_eq_with_tolerance(&(Matrix::identity_matrix(3) * 1e11), 1e-1));
+        assert!(inverse.approx_eq_with_tolerance(&tiny.inverse().expect("Impossible"), 1e-1));
+        let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).expect("Impossible");
+        let p = Matrix::identity_matrix(2) * 1e-11;
+        assert!(a
+            .similarity_transform(&p)
+            .expect("Impossible")
+            .approx_eq(&a));
+        assert!(tiny
+            .sign_function(100, 1e-12)
+            .expect("Impossible")
+            .approx_eq(&Matrix::identity_matrix(3)));
+        assert!(matches!(
+            singular.det_and_inverse_with_tolerance(0.5),
+            Err(Errors::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn vandermonde() {
+        use crate::Matrix;
+        let vandermonde = Matrix::vandermonde(&[1.0, -2.0, 0.5], 2);
+        assert!(
+            vandermonde
+                == Matrix::new(vec![1.0, 1.0, 1.0, 1.0, -2.0, 4.0, 1.0, 0.5, 0.25], (3, 3))
+                    .expect("Impossible")
+        );
+        assert_eq!(Matrix::vandermonde(&[], 2).order, (0, 3));
+    }
+
+    #[test]
+    fn polyfit() {
+        use crate::{Errors, Matrix};
+        // y = 2 - 3x + 0.5x^2
+        let xs = [-1.0, 2.0, 4.0];
+        let ys: Vec<f64> = xs.iter().map(|x| 2.0 - 3.0 * x + 0.5 * x * x).collect();
+        let coefficients = Matrix::polyfit(&xs, &ys, 2).expect("Impossible");
+        assert_eq!(coefficients.len(), 3);
+        for (coefficient, expected) in coefficients.iter().zip([2.0, -3.0, 0.5]) {
+            assert!((coefficient - expected).abs() < 1e-9);
+        }
+
+        assert!(matches!(
+            Matrix::polyfit(&xs, &ys[..2], 2),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        // two points can't determine a quadratic
+        assert!(matches!(
+            Matrix::polyfit(&xs[..2], &ys[..2], 2),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            Matrix::polyfit(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], 1),
+            Err(Errors::SingularMatrix)
+        ));
+
+        // the size of xs doesn't matter, y = 1e6 x
+        let coefficients = Matrix::polyfit(&[1e-6, 2e-6, 3e-6, 4e-6], &[1.0, 2.0, 3.0, 4.0], 1)
+            .expect("Impossible");
+        assert!(coefficients[0].abs() < 1e-9);
+        assert!((coefficients[1] / 1e6 - 1.0).abs() < 1e-9);
+        // y = 1 - 2e12 x^2
+        let xs = [-2e-6, -1e-6, 0.5e-6, 3e-6];
+        let ys: Vec<f64> = xs.iter().map(|x| 1.0 - 2e12 * x * x).collect();
+        let coefficients = Matrix::polyfit(&xs, &ys, 2).expect("Impossible");
+        assert!((coefficients[0] - 1.0).abs() < 1e-9);
+        assert!((coefficients[1] / 1e6).abs() < 1e-9);
+        assert!((coefficients[2] / -2e12 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fold() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 0.5, -1.0], (2, 3)).expect("Impossible");
+        assert_eq!(matrix.fold(1.0, |product, x| product * x), -12.0);
+        // row by row order
+        let order: Vec<f64> = matrix.fold(vec![], |mut items, x| {
+            items.push(x);
+            items
+        });
+        assert_eq!(order, vec![1.0, 2.0, 3.0, 4.0, 0.5, -1.0]);
+    }
+
+    #[test]
+    fn iter_zip() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).expect("Impossible");
+        let b = Matrix::new(vec![2.0, 0.0, 3.0, 1.0], (2, 2)).expect("Impossible");
+        let squared_differences: f64 = a
+            .iter_zip(&b)
+            .expect("Impossible")
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum();
+        assert_eq!(squared_differences, 14.0);
+        assert!(matches!(
+            a.iter_zip(&Matrix::null_matrix((2, 3))),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn distances() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![1.0, -2.0, 3.0, 0.5], (2, 2)).expect("Impossible");
+        assert_eq!(matrix.euclidean_distance(&matrix).expect("Impossible"), 0.0);
+        assert_eq!(matrix.manhattan_distance(&matrix).expect("Impossible"), 0.0);
+
+        let shifted = matrix.clone() + Matrix::generate(|_, _| 2.0, (2, 2));
+        assert_eq!(
+            matrix.euclidean_distance(&shifted).expect("Impossible"),
+            4.0
+        );
+        assert_eq!(
+            matrix.manhattan_distance(&shifted).expect("Impossible"),
+            8.0
+        );
+
+        let other = Matrix::null_matrix((1, 4));
+        assert!(matches!(
+            matrix.euclidean_distance(&other),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        assert!(matches!(
+            matrix.manhattan_distance(&other),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn iterate_until_converged() {
+        use crate::Matrix;
+        // contracting map with the fixed point (2, -1)
+        let target = Matrix::new(vec![2.0, -1.0], (2, 1)).expect("Impossible");
+        let step = |x: &Matrix| (x.clone() + target.clone() * 3.0) / 4.0;
+        let start = Matrix::null_matrix((2, 1));
+
+        let (limit, steps) = start.iterate_until_converged(step, 1e-10, 1000);
+        assert!(limit.approx_eq(&target));
+        assert!(steps > 1 && steps < 1000);
+
+        // stops at max_iter when it can't converge in time
+        let (_, steps) = start.iterate_until_converged(step, 1e-10, 3);
+        assert_eq!(steps, 3);
+        let (same, steps) = start.iterate_until_converged(step, 1e-10, 0);
+        assert!(same == start);
+        assert_eq!(steps, 0);
+    }
+
+    #[test]
+    fn solve_jacobi() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(
+            vec![10.0, -1.0, 2.0, -1.0, 11.0, -1.0, 2.0, -1.0, 10.0],
+            (3, 3),
+        )
+        .expect("Impossible");
+        let b = Matrix::new(vec![6.0, 25.0, -11.0], (3, 1)).expect("Impossible");
+        let direct = a.inverse().expect("Impossible") * b.clone();
+
+        let (x, iterations) = a.solve_jacobi(&b, 1e-12, 200).expect("Impossible");
+        assert!(x.approx_eq_with_tolerance(&direct, 1e-9));
+        assert!(iterations > 1);
+
+        assert!(matches!(
+            a.solve_jacobi(&b, 1e-12, 2),
+            Err(Errors::NotConverged)
+        ));
+        assert!(matches!(
+            a.solve_jacobi(&Matrix::null_matrix((2, 1)), 1e-12, 200),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        let zero_diagonal = Matrix::new(vec![0.0, 1.0, 1.0, 0.0], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            zero_diagonal.solve_jacobi(&Matrix::null_matrix((2, 1)), 1e-12, 200),
+            Err(Errors::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn solve_gauss_seidel() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(
+            vec![10.0, -1.0, 2.0, -1.0, 11.0, -1.0, 2.0, -1.0, 10.0],
+            (3, 3),
+        )
+        .expect("Impossible");
+        let b = Matrix::new(vec![6.0, 25.0, -11.0], (3, 1)).expect("Impossible");
+        let direct = a.inverse().expect("Impossible") * b.clone();
+
+        let (x, iterations) = a.solve_gauss_seidel(&b, 1e-12, 200).expect("Impossible");
+        assert!(x.approx_eq_with_tolerance(&direct, 1e-9));
+        let (_, jacobi_iterations) = a.solve_jacobi(&b, 1e-12, 200).expect("Impossible");
+        assert!(iterations < jacobi_iterations);
+
+        assert!(matches!(
+            a.solve_gauss_seidel(&b, 1e-12, 2),
+            Err(Errors::NotConverged)
+        ));
+    }
+
+    #[test]
+    fn floor_ceil_trunc() {
+        use crate::Matrix;
+        let matrix =
+            Matrix::new(vec![2.7, -2.7, 0.5, -0.5, 3.0, -0.0], (2, 3)).expect("Impossible");
+        assert!(
+            matrix.floor()
+                == Matrix::new(vec![2.0, -3.0, 0.0, -1.0, 3.0, 0.0], (2, 3)).expect("Impossible")
+        );
+        assert!(
+            matrix.ceil()
+                == Matrix::new(vec![3.0, -2.0, 1.0, 0.0, 3.0, 0.0], (2, 3)).expect("Impossible")
+        );
+        assert!(
+            matrix.trunc()
+                == Matrix::new(vec![2.0, -2.0, 0.0, 0.0, 3.0, 0.0], (2, 3)).expect("Impossible")
+        );
+    }
+
+    #[test]
+    fn inverse_into() {
+        use crate::{Errors, Matrix};
+        let mut out = Matrix::null_matrix((3, 3));
+        let buffer = out.items.as_ptr();
+        for matrix in [
+            Matrix::new(vec![1.0, 2.0, 3.0, 3.0, 2.0, 1.0, 2.0, 1.0, 3.0], (3, 3)),
+            Matrix::new(vec![0.0, 2.0, 1.0, 1.0, 0.0, 3.0, 4.0, 1.0, 0.0], (3, 3)),
+        ] {
+            let matrix = matrix.expect("Impossible");
+            matrix.inverse_into(&mut out).expect("Impossible");
+            assert!(out.approx_eq(&matrix.inverse().expect("Impossible")));
+            assert_eq!(out.items.as_ptr(), buffer);
+        }
+
+        let singular = Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            singular.inverse_into(&mut Matrix::null_matrix((2, 2))),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            singular.inverse_into(&mut out),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+
+        // uniformly tiny matrices invert like with inverse
+        let tiny = Matrix::new(vec![4.0, 7.0, 2.0, 6.0], (2, 2)).expect("Impossible") * 1e-11;
+        assert!(!tiny.is_singular().expect("Impossible"));
+        let mut out = Matrix::null_matrix((2, 2));
+        tiny.inverse_into(&mut out).expect("Impossible");
+        let expected = Matrix::new(vec![0.6, -0.7, -0.2, 0.4], (2, 2)).expect("Impossible") * 1e11;
+        assert!(out.approx_eq_with_tolerance(&expected, 1e-1));
+        assert!(out.approx_eq_with_tolerance(&tiny.inverse().expect("Impossible"), 1e-1));
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn generate_parallel() {
+        use crate::Matrix;
+        let f = |i: u32, j: u32| ((i * 31 + j * 17) as f64).sin() * (i as f64).sqrt() / j as f64;
+        for order in [(1, 1), (7, 13), (64, 48), (0, 5), (5, 0)] {
+            assert!(Matrix::generate_parallel(f, order) == Matrix::generate(f, order));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn mul_parallel() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::generate(|i, j| ((i * 7 + j * 3) as f64).sin(), (37, 53));
+        let b = Matrix::generate(|i, j| ((i * 5 + j * 11) as f64).cos() / j as f64, (53, 29));
+        assert!(a.mul_parallel(&b).expect("Impossible") == a.clone() * b.clone());
+
+        let empty = Matrix::null_matrix((3, 0));
+        assert!(
+            empty
+                .mul_parallel(&Matrix::null_matrix((0, 2)))
+                .expect("Impossible")
+                == Matrix::null_matrix((3, 2))
+        );
+        assert!(matches!(
+            a.mul_parallel(&a),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn multiplication_large() {
+        use crate::Matrix;
+        // larger than a block in every dimension
+        let a = Matrix::generate(|i, j| ((i * 7 + j * 3) as f64).sin(), (70, 130));
+        let b = Matrix::generate(|i, j| ((i * 5 + j * 11) as f64).cos() / j as f64, (130, 90));
+        let expected = Matrix::generate(
+            |i, j| {
+                let mut sum = 0.0;
+                for r in 1..=130 {
+                    sum += a[(i, r)] * b[(r, j)];
+                }
+                sum
+            },
+            (70, 90),
+        );
+        assert!(a.clone() * b.clone() == expected);
+
+        let mut product = a;
+        product *= b;
+        assert!(product == expected);
+    }
+
+    #[test]
+    fn get_set_at() {
+        use crate::{Errors, Matrix};
+        let mut matrix = Matrix::new(vec![6.0, 4.0, 87.0, 3.0, 6.0, 89.0, 6.0, 8.0, 4.0], (3, 3))
+            .expect("Impossible");
+        assert_eq!(
+            matrix.get_at((3, 2)).expect("Impossible"),
+            matrix.get(3, 2).expect("Impossible")
+        );
+
+        matrix.set_at((3, 2), -1.0).expect("Impossible");
+        assert_eq!(matrix.get(3, 2).expect("Impossible"), -1.0);
+        assert!(matches!(
+            matrix.set_at((0, 2), 1.0),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn det() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![2.0, -3.0, 1.0, 2.0, 0.0, -1.0, 1.0, 4.0, 5.0], (3, 3))
+            .expect("Impossible");
+        assert_eq!(
+            matrix.det().expect("Impossible"),
+            matrix.to_determinant().expect("Impossible").value()
+        );
+        assert_eq!(matrix.det().expect("Impossible"), 49.0);
+        assert!(matches!(
+            Matrix::null_matrix((3, 2)).det(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn inverse_rank1_update() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0], (3, 3))
+            .expect("Impossible");
+        let u = Matrix::new(vec![1.0, -2.0, 0.5], (3, 1)).expect("Impossible");
+        let v = Matrix::new(vec![0.0, 1.0, 3.0], (3, 1)).expect("Impossible");
+        let inverse = a.inverse().expect("Impossible");
+
+        let updated = inverse.inverse_rank1_update(&u, &v).expect("Impossible");
+        let direct = (a.clone() + u.clone() * v.transpose())
+            .inverse()
+            .expect("Impossible");
+        assert!(updated.approx_eq(&direct));
+
+        // subtracting the first column of A from itself leaves a zero column
+        let u = Matrix::new(vec![-4.0, -1.0, 0.0], (3, 1)).expect("Impossible");
+        let v = Matrix::new(vec![1.0, 0.0, 0.0], (3, 1)).expect("Impossible");
+        assert!(matches!(
+            inverse.inverse_rank1_update(&u, &v),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            inverse.inverse_rank1_update(&Matrix::null_matrix((2, 1)), &v),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+
+        // 1 + v^T A^-1 u is 1e-6, nearly singular
+        let u =
+            Matrix::new(vec![-(1.0 - 1e-6) * 4.0, -1.0 + 1e-6, 0.0], (3, 1)).expect("Impossible");
+        assert!(inverse.inverse_rank1_update(&u, &v).is_ok());
+        assert!(matches!(
+            inverse.inverse_rank1_update_with_tolerance(&u, &v, 1e-5),
+            Err(Errors::SingularMatrix)
+        ));
+    }
+
+    #[test]
+    fn frobenius_inner_product() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![1.0, -2.0, 3.0, 0.5, 4.0, 6.0], (3, 2)).expect("Impossible");
+        let b = Matrix::new(vec![2.0, 1.0, -1.0, 8.0, 0.0, 3.0], (3, 2)).expect("Impossible");
+        let trace_of_product: f64 = (a.transpose() * b.clone())
+            .trace()
+            .expect("Impossible")
+            .iter()
+            .sum();
+        assert_eq!(
+            a.frobenius_inner_product(&b).expect("Impossible"),
+            trace_of_product
+        );
+        assert!(matches!(
+            a.frobenius_inner_product(&a.transpose()),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn from_iter_with_order() {
+        use crate::{Errors, Matrix};
+        let matrix =
+            Matrix::from_iter_with_order((1..=6).map(|x| x as f64), (2, 3)).expect("Impossible");
+        assert_eq!(matrix.order, (2, 3));
+        assert_eq!(matrix.items, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(matches!(
+            Matrix::from_iter_with_order((1..=6).map(|x| x as f64), (3, 3)),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+    }
+
+    #[test]
+    fn permanent() {
+        use crate::{Errors, Matrix};
+        // every row can be matched with every column, 3! matchings
+        let complete = Matrix::generate(|_, _| 1.0, (3, 3));
+        assert_eq!(complete.permanent().expect("Impossible"), 6.0);
+
+        // a 6 cycle has 2 perfect matchings
+        let cycle = Matrix::new(vec![1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0], (3, 3))
+            .expect("Impossible");
+        assert_eq!(cycle.permanent().expect("Impossible"), 2.0);
+
+        assert_eq!(
+            Matrix::identity_matrix(4).permanent().expect("Impossible"),
+            1.0
+        );
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).permanent(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        // 64! is out of reach, the subsets overflow a u64
+        assert!(matches!(
+            Matrix::generate(|_, _| 1.0, (64, 64)).permanent(),
+            Err(Errors::Overflow)
+        ));
+    }
+
+    #[test]
+    fn similarity_transform() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![4.0, 1.0, -2.0, 0.5, 3.0, 1.0, 2.0, -1.0, 6.0], (3, 3))
+            .expect("Impossible");
+        let p = Matrix::new(vec![1.0, 2.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0], (3, 3))
+            .expect("Impossible");
+        let transformed = a.similarity_transform(&p).expect("Impossible");
+
+        let trace = |m: &Matrix| m.trace().expect("Impossible").iter().sum::<f64>();
+        assert!((trace(&transformed) - trace(&a)).abs() < 1e-10);
+        assert!(
+            (transformed.det().expect("Impossible") - a.det().expect("Impossible")).abs() < 1e-9
+        );
+
+        let singular = Matrix::new(vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0], (3, 3))
+            .expect("Impossible");
+        assert!(matches!(
+            a.similarity_transform(&singular),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            a.similarity_transform(&Matrix::identity_matrix(2)),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn difference() {
+        use crate::{Errors, Matrix};
+        let matrix =
+            Matrix::new(vec![1.5, -2.0, 3.0, 7.0, -0.25, 9.0], (2, 3)).expect("Impossible");
+        assert!(matrix.difference(&matrix).expect("Impossible") == Matrix::null_matrix((2, 3)));
+
+        let mut other = matrix.clone();
+        other[(2, 1)] = 5.0;
+        let difference = matrix.difference(&other).expect("Impossible");
+        assert_eq!(difference[(2, 1)], 2.0);
+        assert_eq!(difference.fold(0.0, |sum, x| sum + x), 2.0);
+
+        assert!(matches!(
+            matrix.difference(&matrix.transpose()),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn eye() {
+        use crate::Matrix;
+        let eye = Matrix::eye((3, 2));
+        assert_eq!(eye.order, (3, 2));
+        assert_eq!(eye[(1, 1)], 1.0);
+        assert_eq!(eye[(2, 2)], 1.0);
+        assert_eq!(eye[(1, 2)], 0.0);
+        assert_eq!(eye[(2, 1)], 0.0);
+        assert_eq!(eye[(3, 1)], 0.0);
+        assert_eq!(eye[(3, 2)], 0.0);
+    }
+
+    #[test]
+    fn symmetrize() {
+        use crate::{Errors, Matrix};
+        // symmetric up to accumulated float error
+        let matrix = Matrix::new(
+            vec![
+                2.0,
+                0.1 + 0.2,
+                -1.0,
+                0.3,
+                5.0,
+                1.0 / 3.0,
+                -1.0,
+                1.0 - 2.0 / 3.0,
+                7.0,
+            ],
+            (3, 3),
+        )
+        .expect("Impossible");
+        assert!(matrix != matrix.transpose());
+
+        let symmetric = matrix.symmetrize().expect("Impossible");
+        assert!(symmetric.approx_eq(&symmetric.transpose()));
+        assert!(symmetric.approx_eq(&matrix));
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).symmetrize(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn symmetric_and_skew_parts() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![4.0, -1.0, 2.5, 3.0, 0.0, 7.0, -6.0, 1.0, 2.0], (3, 3))
+            .expect("Impossible");
+        let symmetric = matrix.symmetric_part().expect("Impossible");
+        let skew = matrix.skew_part().expect("Impossible");
+
+        assert!(symmetric == symmetric.transpose());
+        assert!(skew == skew.transpose() * -1.0);
+        assert!((symmetric + skew).approx_eq(&matrix));
+
+        assert!(matches!(
+            Matrix::null_matrix((3, 2)).skew_part(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        assert!(matches!(
+            Matrix::null_matrix((3, 2)).symmetric_part(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn nuclear_norm() {
+        use crate::Matrix;
+        let diagonal = Matrix::new(vec![3.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 0.5], (3, 3))
+            .expect("Impossible");
+        assert!((diagonal.nuclear_norm().expect("Impossible") - 5.5).abs() < 1e-10);
+
+        // rank one, the only singular value is |u| |v| = 3 * 5
+        let u = Matrix::new(vec![1.0, 2.0, 2.0], (3, 1)).expect("Impossible");
+        let v = Matrix::new(vec![3.0, 4.0], (2, 1)).expect("Impossible");
+        let rank_one = u * v.transpose();
+        assert!((rank_one.nuclear_norm().expect("Impossible") - 15.0).abs() < 1e-6);
+
+        assert_eq!(
+            Matrix::null_matrix((2, 2))
+                .nuclear_norm()
+                .expect("Impossible"),
+            0.0
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![4.0, 1.0, -2.0, 1.0, 2.0, 0.0, -2.0, 0.0, 3.0], (3, 3))
+            .expect("Impossible");
+        let (values, vectors) = matrix.symmetric_eigen().expect("Impossible");
+        // A V = V diag(values)
+        let scaled = Matrix::generate(|i, j| vectors[(i, j)] * values[j as usize - 1], (3, 3));
+        assert!((matrix.clone() * vectors.clone()).approx_eq(&scaled));
+        assert!((vectors.transpose() * vectors).approx_eq(&Matrix::identity_matrix(3)));
+        let trace: f64 = matrix.trace().expect("Impossible").iter().sum();
+        assert!((values.iter().sum::<f64>() - trace).abs() < 1e-10);
+    }
+
+    #[test]
+    fn svd() {
+        use crate::Matrix;
+        let reconstruct = |u: &Matrix, s: &[f64], vt: &Matrix| {
+            let sigma = Matrix::generate(
+                |i, j| if i == j { s[i as usize - 1] } else { 0.0 },
+                (s.len() as u32, s.len() as u32),
+            );
+            u.clone() * sigma * vt.clone()
+        };
+
+        let matrix = Matrix::new(vec![3.0, 2.0, 2.0, 3.0, 2.0, -2.0], (3, 2)).expect("Impossible");
+        let (u, s, vt) = matrix.svd().expect("Impossible");
+        assert_eq!((u.order, s.len(), vt.order), ((3, 2), 2, (2, 2)));
+        assert!(s[0] >= s[1] && s[1] >= 0.0);
+        assert!(reconstruct(&u, &s, &vt).approx_eq(&matrix));
+        assert!((u.transpose() * u).approx_eq(&Matrix::identity_matrix(2)));
+        assert!((vt.clone() * vt.transpose()).approx_eq(&Matrix::identity_matrix(2)));
+
+        // wide and rank deficient
+        let wide = Matrix::new(vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0], (2, 3)).expect("Impossible");
+        let (u, s, vt) = wide.svd().expect("Impossible");
+        assert_eq!((u.order, s.len(), vt.order), ((2, 2), 2, (2, 3)));
+        assert_eq!(s[1], 0.0);
+        assert!(reconstruct(&u, &s, &vt).approx_eq(&wide));
+        assert!((u.transpose() * u).approx_eq(&Matrix::identity_matrix(2)));
+        assert!((vt.clone() * vt.transpose()).approx_eq(&Matrix::identity_matrix(2)));
+    }
+
+    #[test]
+    fn det_after_mutation() {
+        use crate::Matrix;
+        // det() reads the items every time, any mutation shows up in the next call
+        let mut matrix = Matrix::new(vec![2.0, 1.0, 1.0, 3.0], (2, 2)).expect("Impossible");
+        assert_eq!(matrix.det().expect("Impossible"), 5.0);
+
+        matrix.set(1, 1, 99.0).expect("Impossible");
+        assert_eq!(matrix.det().expect("Impossible"), 296.0);
+
+        matrix.set_at((2, 2), 1.0).expect("Impossible");
+        assert_eq!(matrix.det().expect("Impossible"), 98.0);
+
+        matrix[(1, 2)] = 0.0;
+        assert_eq!(matrix.det().expect("Impossible"), 99.0);
+
+        matrix[2] = 5.0;
+        assert_eq!(matrix.det().expect("Impossible"), 99.0);
+
+        matrix.transpose_mut();
+        assert_eq!(matrix.det().expect("Impossible"), 99.0);
+
+        matrix *= 2.0;
+        assert_eq!(matrix.det().expect("Impossible"), 396.0);
+
+        matrix += Matrix::identity_matrix(2);
+        assert_eq!(matrix.det().expect("Impossible"), 597.0);
+    }
+
+    #[test]
+    fn to_latex() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![1.0, -0.5, 3.25, 4.0], (2, 2)).expect("Impossible");
+        let latex = matrix.to_latex();
+        assert!(latex.starts_with("\\begin{pmatrix}"));
+        assert!(latex.ends_with("\\end{pmatrix}"));
+        assert!(latex.contains("1 & -0.5 \\\\"));
+        assert!(latex.contains("3.25 & 4"));
+
+        let latex = matrix.to_latex_precision(1);
+        assert!(latex.contains("1.0 & -0.5 \\\\"));
+        assert!(latex.contains("3.2 & 4.0"));
+    }
+
+    #[test]
+    fn to_markdown() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![7.0, -0.25, 10.0, 3.5], (2, 2)).expect("Impossible");
+        let markdown = matrix.to_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "| 1 | 2 |");
+        assert_eq!(lines[1], "|---|---|");
+        for value in ["7", "-0.25", "10", "3.5"] {
+            assert!(markdown.contains(&format!(" {} |", value)));
+        }
+    }
+
+    #[test]
+    fn display_alignment() {
+        use crate::Matrix;
+        let matrix =
+            Matrix::new(vec![1.0, 100.5, -2.0, 100.5, 1.0, 3.0], (2, 3)).expect("Impossible");
+        let display = matrix.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!(lines, vec!["    1  100.5  -2", "100.5      1   3"]);
+        assert!(!display.contains(".0"));
+    }
+
+    #[test]
+    fn display_whitespace() {
+        use crate::Matrix;
+        let matrix =
+            Matrix::new(vec![1.5, 20.0, 300.0, -4.0, 5.0, 6.25], (3, 2)).expect("Impossible");
+        let display = matrix.to_string();
+        assert!(!display.ends_with('\n'));
+        assert_eq!(display.split('\n').count(), 3);
+        for line in display.split('\n') {
+            assert!(!line.is_empty());
+            assert_eq!(line, line.trim_end());
+        }
+        assert_eq!(display, "1.5    20\n300    -4\n  5  6.25");
     }
-}
-impl Index<(u32, u32)> for Matrix {
-    type Output = f64;
-    fn index(&self, (i, j): (u32, u32)) -> &Self::Output {
-        &self.items[((i - 1) * self.order.1 + (j - 1)) as usize]
+
+    #[test]
+    fn into_determinant() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![2.0, -3.0, 1.0, 2.0, 0.0, -1.0, 1.0, 4.0, 5.0], (3, 3))
+            .expect("Impossible");
+        let cloned = matrix.to_determinant().expect("Impossible");
+        let moved = matrix.into_determinant().expect("Impossible");
+        assert_eq!(moved.value(), cloned.value());
+        assert_eq!(moved.size, 3);
+
+        assert!(matches!(
+            Matrix::null_matrix((1, 4)).into_determinant(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
     }
-}
-impl IndexMut<(u32, u32)> for Matrix {
-    fn index_mut(&mut self, (i, j): (u32, u32)) -> &mut Self::Output {
-        &mut self.items[((i - 1) * self.order.1 + (j - 1)) as usize]
+
+    #[test]
+    fn set_many() {
+        use crate::{Errors, Matrix};
+        let mut matrix = Matrix::null_matrix((3, 3));
+        matrix
+            .set_many(&[((1, 3), 5.0), ((2, 2), -1.0), ((3, 1), 2.5)])
+            .expect("Impossible");
+        assert!(
+            matrix
+                == Matrix::new(vec![0.0, 0.0, 5.0, 0.0, -1.0, 0.0, 2.5, 0.0, 0.0], (3, 3))
+                    .expect("Impossible")
+        );
+
+        let before = matrix.clone();
+        assert!(matches!(
+            matrix.set_many(&[((1, 1), 7.0), ((2, 4), 1.0), ((3, 3), 8.0)]),
+            Err(Errors::IndexOutOfRange)
+        ));
+        assert!(matrix == before);
     }
-}
-impl Display for Matrix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut print = String::new();
-        let mut largest_item_size = 0;
-        for item in self.items.iter() {
-            let size = item.to_string().len();
-            if size > largest_item_size {
-                largest_item_size = size;
-            }
+
+    #[test]
+    fn deviatoric() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![0.1, 2.0, -4.0, 1.0, 0.7, 3.0, 5.0, 6.0, 9.3], (3, 3))
+            .expect("Impossible");
+        let deviatoric = matrix.deviatoric().expect("Impossible");
+        let trace: f64 = deviatoric.trace().expect("Impossible").iter().sum();
+        assert!(trace.abs() < 1e-12);
+        assert_eq!(deviatoric[(1, 2)], matrix[(1, 2)]);
+
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).deviatoric(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn solve_sylvester() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![3.0, 1.0, 0.0, 0.0, 2.0, -1.0, 1.0, 0.0, 4.0], (3, 3))
+            .expect("Impossible");
+        let b = Matrix::new(vec![1.0, 2.0, -1.0, 3.0], (2, 2)).expect("Impossible");
+        let c = Matrix::new(vec![1.0, 0.0, 2.0, -3.0, 4.0, 5.0], (3, 2)).expect("Impossible");
+        let x = Matrix::solve_sylvester(&a, &b, &c).expect("Impossible");
+        assert_eq!(x.order, (3, 2));
+        assert!((a.clone() * x.clone() + x * b.clone()).approx_eq(&c));
+
+        assert!(matches!(
+            Matrix::solve_sylvester(&a, &b, &c.transpose()),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        // A and -B share the eigenvalue 1, so the solution isn't unique
+        let a = Matrix::identity_matrix(2);
+        let b = Matrix::identity_matrix(2) * -1.0;
+        assert!(matches!(
+            Matrix::solve_sylvester(&a, &b, &Matrix::null_matrix((2, 2))),
+            Err(Errors::SingularMatrix)
+        ));
+
+        // uniformly tiny coefficients still have a unique solution, X = I / 2e-11
+        let tiny = Matrix::identity_matrix(2) * 1e-11;
+        let x =
+            Matrix::solve_sylvester(&tiny, &tiny, &Matrix::identity_matrix(2)).expect("Impossible");
+        assert!(x.approx_eq_with_tolerance(&(Matrix::identity_matrix(2) * 5e10), 1e-1));
+    }
+
+    #[test]
+    fn zero_based_accessors() {
+        use crate::{Errors, Matrix};
+        let mut matrix =
+            Matrix::new(vec![6.0, 4.0, 87.0, 3.0, 6.0, 89.0], (2, 3)).expect("Impossible");
+        assert_eq!(
+            matrix.get0(0, 0).expect("Impossible"),
+            matrix.get(1, 1).expect("Impossible")
+        );
+        assert_eq!(
+            matrix.get0(1, 2).expect("Impossible"),
+            matrix.get(2, 3).expect("Impossible")
+        );
+        assert_eq!(
+            matrix.get_row0(1).expect("Impossible"),
+            matrix.get_row(2).expect("Impossible")
+        );
+        assert_eq!(
+            matrix.get_column0(2).expect("Impossible"),
+            matrix.get_column(3).expect("Impossible")
+        );
+
+        matrix.set0(0, 2, -1.0).expect("Impossible");
+        assert_eq!(matrix[(1, 3)], -1.0);
+
+        // the column must be checked too, (0, 3) would be (1, 0) in the flat items
+        assert!(matches!(matrix.get0(0, 3), Err(Errors::IndexOutOfRange)));
+        assert!(matches!(
+            matrix.set0(2, 0, 1.0),
+            Err(Errors::IndexOutOfRange)
+        ));
+        assert!(matches!(matrix.get_row0(2), Err(Errors::IndexOutOfRange)));
+        assert!(matches!(
+            matrix.get_column0(u32::MAX),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn diagonalize() {
+        use crate::{Errors, Matrix};
+        let symmetric = Matrix::new(vec![4.0, 1.0, 1.0, 3.0], (2, 2)).expect("Impossible");
+        let (p, d) = symmetric.diagonalize().expect("Impossible");
+        assert_eq!((d[(1, 2)], d[(2, 1)]), (0.0, 0.0));
+        let reconstructed = p.clone() * d * p.inverse().expect("Impossible");
+        assert!((reconstructed * 1e6).round() == symmetric * 1e6);
+
+        // not symmetric, eigenvalues 2, 3 and a repeated 1
+        let matrix = Matrix::new(vec![1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0], (3, 3))
+            .expect("Impossible");
+        let basis = Matrix::new(vec![1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 2.0], (3, 3))
+            .expect("Impossible");
+        let matrix = basis.clone() * matrix * basis.inverse().expect("Impossible");
+        let (p, d) = matrix.diagonalize().expect("Impossible");
+        assert!((p.clone() * d.clone() * p.inverse().expect("Impossible"))
+            .approx_eq_with_tolerance(&matrix, 1e-8));
+        let mut eigenvalues = d.trace().expect("Impossible");
+        eigenvalues.sort_by(f64::total_cmp);
+        for (value, expected) in eigenvalues.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((value - expected).abs() < 1e-8);
         }
 
-        for (i, item) in self.items.iter().enumerate() {
-            print += &format!(
-                "{}{}  ",
-                item,
-                " ".repeat((largest_item_size - item.to_string().len()) as usize)
-            );
-            if (i as u32 + 1) % self.order.1 == 0 {
-                print += "\n";
-            }
+        let repeated = Matrix::new(vec![2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 1.0, 1.0, 5.0], (3, 3))
+            .expect("Impossible");
+        let (p, d) = repeated.diagonalize().expect("Impossible");
+        assert!((p.clone() * d * p.inverse().expect("Impossible"))
+            .approx_eq_with_tolerance(&repeated, 1e-8));
+
+        let defective = Matrix::new(vec![2.0, 1.0, 0.0, 2.0], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            defective.diagonalize(),
+            Err(Errors::NotDiagonalizable)
+        ));
+        // a rotation only has complex eigenvalues
+        let rotation = Matrix::new(vec![0.0, -1.0, 1.0, 0.0], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            rotation.diagonalize(),
+            Err(Errors::NotDiagonalizable)
+        ));
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).diagonalize(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn solve_checked() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(
+            vec![4.0, -2.0, 1.0, -2.0, 4.0, -2.0, 1.0, -2.0, 4.0],
+            (3, 3),
+        )
+        .expect("Impossible");
+        let x = Matrix::new(vec![1.0, -1.0, 2.0, 0.5, 3.0, 0.0], (3, 2)).expect("Impossible");
+        let b = a.clone() * x.clone();
+        assert!(a.solve_checked(&b, 1e-6).expect("Impossible").approx_eq(&x));
+
+        // passes the singularity check but its rcond is about 2.5e-10
+        let nearly_singular =
+            Matrix::new(vec![1.0, 1.0, 1.0, 1.0 + 1e-9], (2, 2)).expect("Impossible");
+        assert!(!nearly_singular.is_singular().expect("Impossible"));
+        let b = Matrix::new(vec![2.0, 2.0], (2, 1)).expect("Impossible");
+        assert!(matches!(
+            nearly_singular.solve_checked(&b, 1e-6),
+            Err(Errors::IllConditioned)
+        ));
+        assert!(nearly_singular.solve_checked(&b, 1e-12).is_ok());
+
+        let singular = Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            singular.solve_checked(&b, 1e-6),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            a.solve_checked(&b, 1e-6),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn flatten() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).expect("Impossible");
+        let row = matrix.flatten_row();
+        assert_eq!(row.order, (1, 6));
+        assert_eq!(row.items, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let column = matrix.flatten_column();
+        assert_eq!(column.order, (6, 1));
+        assert!(column == row.transpose());
+    }
+
+    #[test]
+    fn trace_of_power() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![2.0, -1.0, 0.5, 3.0, 0.0, 1.0, -2.0, 4.0, 1.0], (3, 3))
+            .expect("Impossible");
+        let trace_sum = |m: Matrix| m.trace().expect("Impossible").iter().sum::<f64>();
+        assert_eq!(
+            matrix.trace_of_power(2).expect("Impossible"),
+            trace_sum(matrix.clone() * matrix.clone())
+        );
+        assert_eq!(
+            matrix.trace_of_power(1).expect("Impossible"),
+            trace_sum(matrix.clone())
+        );
+        assert!(
+            (matrix.trace_of_power(4).expect("Impossible")
+                - trace_sum(matrix.clone() * matrix.clone() * matrix.clone() * matrix.clone()))
+            .abs()
+                < 1e-9
+        );
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).trace_of_power(2),
+            Err(Errors::TraceExistsOnlyForSquareMatrices)
+        ));
+    }
+
+    #[test]
+    fn is_stochastic() {
+        use crate::Matrix;
+        let valid = Matrix::new(vec![0.2, 0.3, 0.5, 1.0, 0.0, 0.0, 0.1, 0.1, 0.8], (3, 3))
+            .expect("Impossible");
+        assert!(valid.is_row_stochastic(1e-12));
+        assert!(valid.transpose().is_column_stochastic(1e-12));
+
+        let too_much = Matrix::new(vec![0.2, 0.3, 0.6, 1.0, 0.0, 0.0, 0.1, 0.1, 0.8], (3, 3))
+            .expect("Impossible");
+        assert!(!too_much.is_row_stochastic(1e-12));
+        assert!(too_much.is_row_stochastic(0.2));
+
+        let negative = Matrix::new(vec![1.5, -0.5, 0.5, 0.5], (2, 2)).expect("Impossible");
+        assert!(!negative.is_row_stochastic(1e-12));
+    }
+
+    #[test]
+    fn steady_state() {
+        use crate::{Errors, Matrix};
+        // leaves state 1 with 0.1 and state 2 with 0.3, so it spends 3/4 of the time in state 1
+        let chain = Matrix::new(vec![0.9, 0.1, 0.3, 0.7], (2, 2)).expect("Impossible");
+        let steady = chain.steady_state(1e-13, 10_000).expect("Impossible");
+        assert_eq!(steady.order, (1, 2));
+        assert!(steady.approx_eq(&Matrix::new(vec![0.75, 0.25], (1, 2)).expect("Impossible")));
+        assert!((steady.clone() * chain.clone()).approx_eq(&steady));
+
+        assert!(matches!(
+            chain.steady_state(1e-13, 2),
+            Err(Errors::NotConverged)
+        ));
+        let not_stochastic = Matrix::new(vec![0.9, 0.2, 0.3, 0.7], (2, 2)).expect("Impossible");
+        assert!(matches!(
+            not_stochastic.steady_state(1e-13, 10_000),
+            Err(Errors::NotStochastic)
+        ));
+        assert!(matches!(
+            Matrix::new(vec![0.5, 0.5], (1, 2))
+                .expect("Impossible")
+                .steady_state(1e-13, 10_000),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn clone_into_buffer() {
+        use crate::Matrix;
+        let mut dst = Matrix::null_matrix((3, 2));
+        let buffer = dst.items.as_ptr();
+        for step in 0..3 {
+            let matrix = Matrix::generate(|i, j| (i * j + step) as f64, (2, 3));
+            matrix.clone_into_buffer(&mut dst);
+            assert!(dst == matrix);
+            assert_eq!(dst.items.as_ptr(), buffer);
         }
-        f.write_str(&print)
+
+        // grows when it has to
+        let large = Matrix::identity_matrix(5);
+        large.clone_into_buffer(&mut dst);
+        assert!(dst == large);
     }
-}
 
-#[cfg(test)]
-mod tests {
     #[test]
-    fn addition() {
+    fn generate_0based() {
         use crate::Matrix;
-        let a = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (3, 2)).expect("Impossible");
-        let b = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+        let columns = 4;
+        let matrix = Matrix::generate_0based(|i, j| (i * columns + j) as f64, (3, columns));
+        assert_eq!(
+            matrix.items,
+            (0..12).map(|x| x as f64).collect::<Vec<f64>>()
+        );
+        assert!(matrix == Matrix::generate(|i, j| ((i - 1) * columns + j - 1) as f64, (3, 4)));
+    }
 
-        let sum = a + b;
-        assert_eq!(sum.order, (3, 2));
-        assert_eq!(sum.items, vec![49.0, 89.0, 67.0, 998.0, 18.0, 105.0]);
+    #[test]
+    fn same_shape_and_data() {
+        use crate::Matrix;
+        let items = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let wide = Matrix::new(items.clone(), (2, 3)).expect("Impossible");
+        let tall = Matrix::new(items, (3, 2)).expect("Impossible");
+        assert!(wide.same_data(&tall));
+        assert!(!wide.same_shape(&tall));
+        assert!(wide != tall);
+
+        let other = Matrix::null_matrix((2, 3));
+        assert!(wide.same_shape(&other));
+        assert!(!wide.same_data(&other));
+        assert!(wide.same_shape(&wide) && wide.same_data(&wide));
     }
 
     #[test]
-    fn subtraction() {
+    fn log() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(
+            vec![0.1, -0.2, 0.05, 0.15, 0.0, 0.1, -0.1, 0.2, 0.05],
+            (3, 3),
+        )
+        .expect("Impossible");
+        // exp(A) by its own series
+        let mut exp = Matrix::identity_matrix(3);
+        let mut term = Matrix::identity_matrix(3);
+        for k in 1..30 {
+            term = term * a.clone() / k as f64;
+            exp += term.clone();
+        }
+        assert!(exp.log(200).expect("Impossible").approx_eq(&a));
+
+        assert!(
+            Matrix::identity_matrix(2).log(5).expect("Impossible") == Matrix::null_matrix((2, 2))
+        );
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).log(5),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        // X = 2I, the series diverges
+        assert!(matches!(
+            (Matrix::identity_matrix(2) * 3.0).log(100),
+            Err(Errors::NotConverged)
+        ));
+    }
+
+    #[test]
+    fn sign_function() {
+        use crate::{Errors, Matrix};
+        // eigenvalues 1 and -3
+        let matrix = Matrix::new(vec![1.0, 2.0, 0.0, -3.0], (2, 2)).expect("Impossible");
+        let sign = matrix.sign_function(100, 1e-12).expect("Impossible");
+        assert!(
+            sign.approx_eq(&Matrix::new(vec![1.0, 1.0, 0.0, -1.0], (2, 2)).expect("Impossible"))
+        );
+        assert!((sign.clone() * sign.clone()).approx_eq(&Matrix::identity_matrix(2)));
+        // one eigenvalue on each side
+        assert!(sign.trace().expect("Impossible").iter().sum::<f64>().abs() < 1e-10);
+
+        assert!(matches!(
+            Matrix::new(vec![1.0, 2.0, 2.0, 4.0], (2, 2))
+                .expect("Impossible")
+                .sign_function(100, 1e-12),
+            Err(Errors::SingularMatrix)
+        ));
+        assert!(matches!(
+            matrix.sign_function(1, 1e-12),
+            Err(Errors::NotConverged)
+        ));
+        assert!(matches!(
+            Matrix::null_matrix((1, 2)).sign_function(100, 1e-12),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn from_columns() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::from_columns(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])
+            .expect("Impossible");
+        assert_eq!(matrix.order, (3, 2));
+        assert_eq!(matrix.items, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        assert_eq!(
+            matrix.get_column(2).expect("Impossible"),
+            vec![4.0, 5.0, 6.0]
+        );
+
+        assert!(matches!(
+            Matrix::from_columns(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0]]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+        assert_eq!(
+            Matrix::from_columns(vec![]).expect("Impossible").order,
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn masked() {
+        use crate::{Errors, Matrix};
+        let dense = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], (3, 3))
+            .expect("Impossible");
+        let upper = Matrix::generate(|i, j| if i <= j { 1.0 } else { 0.0 }, (3, 3));
+        assert!(
+            dense.masked(&upper).expect("Impossible")
+                == Matrix::new(vec![1.0, 2.0, 3.0, 0.0, 5.0, 6.0, 0.0, 0.0, 9.0], (3, 3))
+                    .expect("Impossible")
+        );
+        assert!(matches!(
+            dense.masked(&Matrix::identity_matrix(2)),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn with_rows() {
         use crate::Matrix;
-        let a = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (3, 2)).expect("Impossible");
-        let b = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).expect("Impossible");
+        let grown = matrix.with_rows(4, -1.0);
+        assert_eq!(grown.order, (4, 2));
+        assert_eq!(
+            grown.items,
+            vec![1.0, 2.0, 3.0, 4.0, -1.0, -1.0, -1.0, -1.0]
+        );
+        let shrunk = matrix.with_rows(1, -1.0);
+        assert_eq!(shrunk.order, (1, 2));
+        assert_eq!(shrunk.items, vec![1.0, 2.0]);
+        assert!(matrix.with_rows(2, -1.0) == matrix);
 
-        let diff = a - b;
-        assert_eq!(diff.order, (3, 2));
-        assert_eq!(diff.items, vec![41.0, -85.0, 63.0, 800.0, -6.0, 17.0]);
+        let wide = matrix.with_columns(3, -1.0);
+        assert_eq!(wide.items, vec![1.0, 2.0, -1.0, 3.0, 4.0, -1.0]);
+        assert!(wide.with_columns(2, 0.0) == matrix);
     }
 
     #[test]
-    fn multiplication() {
+    fn len() {
         use crate::Matrix;
-        let a = Matrix::new(vec![4.0, 87.0, 2.0, 99.0, 12.0, 44.0], (3, 2)).expect("Impossible");
+        let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (3, 2)).expect("Impossible");
+        assert_eq!(matrix.len(), 6);
+        for order in [(1, 1), (1, 4), (4, 1), (3, 3)] {
+            let items = vec![1.0; order.0 as usize * order.1 as usize];
+            assert!(!Matrix::new(items, order).expect("Impossible").is_empty());
+        }
+        // only a zero order gives an empty matrix
+        assert!(Matrix::new(vec![], (0, 2)).expect("Impossible").is_empty());
+    }
 
-        let scalar_mul = a.clone() * 5.0;
-        assert_eq!(scalar_mul.order, (3, 2));
+    #[test]
+    fn position_of() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![0.0, 7.0, 0.5, 7.0, 0.0, 0.0], (2, 3)).expect("Impossible");
+        // the first match row by row
+        assert_eq!(matrix.position_of(7.0, 1e-10), Some((1, 2)));
+        assert_eq!(matrix.position_of(0.5 + 1e-12, 1e-10), Some((1, 3)));
+        assert!(matrix.contains(7.0, 0.0));
+
+        assert_eq!(matrix.position_of(3.0, 1e-10), None);
+        assert!(!matrix.contains(3.0, 1e-10));
+        assert!(matrix.contains(3.0, 2.5));
+    }
+
+    #[test]
+    fn means() {
+        use crate::Matrix;
+        let data = Matrix::new(vec![1.0, -2.0, 0.5, 3.0, 4.0, 2.5], (2, 3)).expect("Impossible");
+        assert_eq!(data.column_means(), vec![2.0, 1.0, 1.5]);
+        assert_eq!(data.row_means(), vec![-0.5 / 3.0, 9.5 / 3.0]);
+        assert_eq!(data.column_sums(), vec![4.0, 2.0, 3.0]);
+        // a row has a single observation of every variable
+        let row = Matrix::row_matrix(vec![1.0, 2.0]);
+        assert_eq!(row.column_means(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn pca() {
+        use crate::{Errors, Matrix};
+        // points along the direction (2, 1) with a little noise across it
+        let along = [-3.0, -1.0, 0.0, 2.0, 4.0];
+        let across = [0.1, -0.1, 0.05, -0.05, 0.0];
+        let items = along
+            .iter()
+            .zip(across)
+            .flat_map(|(&t, s)| [2.0 * t - s, t + 2.0 * s])
+            .collect();
+        let data = Matrix::new(items, (5, 2)).expect("Impossible");
+
+        let (components, variances) = data.pca(2).expect("Impossible");
+        assert_eq!(components.order, (2, 2));
+        assert!(variances[0] > variances[1]);
+        let norm = 5.0_f64.sqrt();
+        // the sign of an eigenvector is arbitrary
+        let alignment = (components[(1, 1)] * 2.0 + components[(2, 1)]) / norm;
+        assert!((alignment.abs() - 1.0).abs() < 1e-3);
+        // the variances add up to the total variance
+        let covariance = data.covariance();
+        assert!(
+            (variances[0] + variances[1] - covariance[(1, 1)] - covariance[(2, 2)]).abs() < 1e-9
+        );
+
+        assert!(matches!(data.pca(3), Err(Errors::IndexOutOfRange)));
+        assert!(matches!(
+            Matrix::row_matrix(vec![1.0, 2.0]).pca(1),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+    }
+
+    #[test]
+    fn push_row() {
+        use crate::{Errors, Matrix};
+        let square = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).expect("Impossible");
+
+        let mut tall = square.clone();
+        tall.push_row(vec![5.0, 6.0]).expect("Impossible");
+        assert_eq!(tall.order, (3, 2));
+        assert_eq!(tall.get_row(3).expect("Impossible"), vec![5.0, 6.0]);
+        assert!(matches!(
+            tall.push_row(vec![1.0, 2.0, 3.0]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+        assert_eq!(tall.order, (3, 2));
+
+        let mut wide = square.clone();
+        wide.push_column(vec![5.0, 6.0]).expect("Impossible");
+        assert_eq!(wide.order, (2, 3));
+        assert_eq!(wide.items, vec![1.0, 2.0, 5.0, 3.0, 4.0, 6.0]);
+        assert!(matches!(
+            wide.push_column(vec![]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+
+        // building from nothing
+        let mut streamed = Matrix::null_matrix((0, 0));
+        streamed.push_row(vec![1.0, 2.0]).expect("Impossible");
+        streamed.push_row(vec![3.0, 4.0]).expect("Impossible");
+        assert!(streamed == square);
+        let mut streamed = Matrix::null_matrix((0, 0));
+        streamed.push_column(vec![1.0, 3.0]).expect("Impossible");
+        streamed.push_column(vec![2.0, 4.0]).expect("Impossible");
+        assert!(streamed == square);
+
+        // only a (0, 0) matrix adopts the length
+        let mut no_rows = Matrix::null_matrix((0, 3));
+        assert!(matches!(
+            no_rows.push_row(vec![1.0]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+        assert_eq!(no_rows.order, (0, 3));
+        no_rows.push_row(vec![1.0, 2.0, 3.0]).expect("Impossible");
+        assert_eq!(no_rows.order, (1, 3));
+        let mut no_columns = Matrix::null_matrix((2, 0));
+        assert!(matches!(
+            no_columns.push_column(vec![1.0, 2.0, 3.0]),
+            Err(Errors::InappropriateNumberOfItems)
+        ));
+        assert_eq!(no_columns.order, (2, 0));
+        no_columns.push_column(vec![1.0, 2.0]).expect("Impossible");
+        assert_eq!(no_columns.order, (2, 1));
+        assert_eq!(no_columns.items, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn inverse_fast_path() {
+        use crate::Matrix;
+        let diagonal = Matrix::diagonal_matrix(vec![2.0, -4.0, 0.5, 8.0]);
+        let inverse = diagonal.inverse().expect("Impossible");
+        assert!(inverse == Matrix::diagonal_matrix(vec![0.5, -0.25, 2.0, 0.125]));
+        assert!(inverse.approx_eq(&diagonal.adjoint_inverse().expect("Impossible")));
+
+        let upper = Matrix::new(vec![2.0, 1.0, -3.0, 0.0, 4.0, 5.0, 0.0, 0.0, -1.0], (3, 3))
+            .expect("Impossible");
+        let inverse = upper.inverse().expect("Impossible");
+        assert!(inverse.approx_eq(&upper.adjoint_inverse().expect("Impossible")));
+        assert!((upper.clone() * inverse).approx_eq(&Matrix::identity_matrix(3)));
+
+        let lower = upper.transpose();
+        let inverse = lower.inverse().expect("Impossible");
+        assert!(inverse.approx_eq(&lower.adjoint_inverse().expect("Impossible")));
+        assert!((lower * inverse).approx_eq(&Matrix::identity_matrix(3)));
+
+        // a zero on the diagonal takes the general path like before
+        let singular = Matrix::diagonal_matrix(vec![1.0, 0.0]);
+        let inverse = singular.inverse().expect("Impossible");
+        assert!(!inverse.items.iter().all(|x| x.is_finite()));
+    }
+
+    #[test]
+    fn sort_rows_by_column() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![1.0, 5.0, 2.0, -1.0, 3.0, 7.0], (3, 2)).expect("Impossible");
+        let sorted = matrix.sort_rows_by_column(2, false).expect("Impossible");
+        assert!(
+            sorted == Matrix::new(vec![3.0, 7.0, 1.0, 5.0, 2.0, -1.0], (3, 2)).expect("Impossible")
+        );
+
+        // ties keep their order whatever the direction
+        let ties = Matrix::new(vec![1.0, 0.0, 2.0, 1.0, 3.0, 0.0], (3, 2)).expect("Impossible");
+        let ascending = ties.sort_rows_by_column(2, true).expect("Impossible");
         assert_eq!(
-            scalar_mul.items,
-            vec![20.0, 435.0, 10.0, 495.0, 60.0, 220.0]
+            ascending.get_column(1).expect("Impossible"),
+            vec![1.0, 3.0, 2.0]
+        );
+        let descending = ties.sort_rows_by_column(2, false).expect("Impossible");
+        assert_eq!(
+            descending.get_column(1).expect("Impossible"),
+            vec![2.0, 1.0, 3.0]
         );
 
-        let b = Matrix::new(vec![45.0, 2.0, 65.0, 899.0, 6.0, 61.0], (2, 3)).expect("Impossible");
+        assert!(matches!(
+            matrix.sort_rows_by_column(0, true),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
 
-        let matrix_mul = a * b;
-        assert_eq!(matrix_mul.order, (3, 3));
+    #[test]
+    fn unique_rows() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![5.0, 1.0, 2.0, 3.0, 5.0, 1.0 + 1e-12, 0.0, 0.0], (4, 2))
+            .expect("Impossible");
+        let unique = matrix.unique_rows(1e-10);
+        assert_eq!(unique.order, (3, 2));
+        assert_eq!(unique.items, vec![5.0, 1.0, 2.0, 3.0, 0.0, 0.0]);
+        // with no tolerance the rows differ
+        assert_eq!(matrix.unique_rows(0.0).order, (4, 2));
+
+        // empty rows are all the same
+        assert_eq!(Matrix::null_matrix((3, 0)).unique_rows(0.0).order, (1, 0));
+        assert_eq!(Matrix::null_matrix((0, 0)).unique_rows(0.0).order, (0, 0));
+        assert_eq!(Matrix::null_matrix((0, 2)).unique_rows(0.0).order, (0, 2));
+    }
+
+    #[test]
+    fn weighted_row_combination() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::generate(|i, j| (i * i) as f64 - 0.5 * j as f64, (3, 4));
+        let weights = [0.25, -2.0, 3.0];
+        let combination = matrix
+            .weighted_row_combination(&weights)
+            .expect("Impossible");
+        let product = Matrix::row_matrix(weights.to_vec()) * matrix.clone();
+        assert_eq!(combination.order, (1, 4));
+        assert!(combination.approx_eq(&product));
+
+        assert!(matches!(
+            matrix.weighted_row_combination(&[1.0, 2.0]),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn determinant_block_triangular() {
+        use crate::{Errors, Matrix};
+        // A = [[2, 1], [1, 3]] with det 5 and C = [[4, -1], [2, 1]] with det 6
+        let matrix = Matrix::new(
+            vec![
+                2.0, 1.0, 9.0, -4.0, //
+                1.0, 3.0, 0.5, 7.0, //
+                0.0, 0.0, 4.0, -1.0, //
+                0.0, 0.0, 2.0, 1.0,
+            ],
+            (4, 4),
+        )
+        .expect("Impossible");
+        let det = matrix.determinant_block_triangular(2).expect("Impossible");
+        assert!((det - 30.0).abs() < 1e-12);
+        assert!((det - matrix.det().expect("Impossible")).abs() < 1e-9);
+
+        // the block under A is not zero for this split
+        assert!(matches!(
+            matrix.determinant_block_triangular(1),
+            Err(Errors::NotBlockTriangular)
+        ));
+        assert!(matches!(
+            matrix.determinant_block_triangular(4),
+            Err(Errors::IndexOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn scalar_assign_in_place() {
+        use crate::Matrix;
+        let mut matrix = Matrix::new(vec![1.0, -2.5, 3.0, 0.0], (2, 2)).expect("Impossible");
+        let buffer = matrix.items.as_ptr();
+        matrix *= 2.0;
+        assert_eq!(matrix.items, vec![2.0, -5.0, 6.0, 0.0]);
+        matrix /= 4.0;
+        assert_eq!(matrix.items, vec![0.5, -1.25, 1.5, 0.0]);
+        assert_eq!(matrix.order, (2, 2));
+        // no new allocation
+        assert_eq!(matrix.items.as_ptr(), buffer);
+    }
+
+    #[test]
+    fn mul_vec() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![2.0, -1.0, 0.5, 0.0, 3.0, 4.0], (2, 3)).expect("Impossible");
+        let v = [4.0, 2.0, -2.0];
+        let product = matrix.mul_vec(&v).expect("Impossible");
+        assert_eq!(product, vec![5.0, -2.0]);
+        let column = matrix.clone() * Matrix::column_matrix(v.to_vec());
+        assert_eq!(product, column.get_column(1).expect("Impossible"));
+
+        assert!(matches!(
+            matrix.mul_vec(&[1.0, 2.0]),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        // no columns gives zeros
         assert_eq!(
-            matrix_mul.items,
-            vec![78393.0, 530.0, 5567.0, 89091.0, 598.0, 6169.0, 40096.0, 288.0, 3464.0]
+            Matrix::null_matrix((2, 0))
+                .mul_vec(&[])
+                .expect("Impossible"),
+            vec![0.0, 0.0]
         );
+    }
 
-        let matrix =
-            Matrix::new(vec![1.0, 6.0, 4.0, 2.0, 5.0, 7.0, 4.0, 2.0, 9.0], (3, 3)).unwrap();
-        let inverse = matrix.inverse().unwrap();
+    #[test]
+    fn hadamard_pow() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![1.5, -2.0, 16.0, 0.0], (2, 2)).expect("Impossible");
+        let exponents = Matrix::new(vec![2.0, 3.0, 0.25, 0.0], (2, 2)).expect("Impossible");
+        let powered = matrix.hadamard_pow(&exponents).expect("Impossible");
+        assert_eq!(powered.items, vec![2.25, -8.0, 2.0, 1.0]);
 
-        // Rounding because 0.999999991 is just 1
-        assert!((matrix * inverse).round() == Matrix::identity_matrix(3));
+        assert!(matches!(
+            matrix.hadamard_pow(&Matrix::row_matrix(vec![1.0, 2.0, 3.0, 4.0])),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn trace_rectangular() {
+        use crate::Matrix;
+        let wide = Matrix::new(vec![1.5, 2.0, 3.0, 4.0, -5.0, 6.0], (2, 3)).expect("Impossible");
+        assert_eq!(wide.trace_rectangular(), -3.5);
+        assert_eq!(wide.transpose().trace_rectangular(), -3.5);
+        assert!(wide.trace().is_err());
+
+        let square = Matrix::generate(|i, j| (i * 10 + j) as f64, (3, 3));
+        let trace: f64 = square.trace().expect("Impossible").iter().sum();
+        assert_eq!(square.trace_rectangular(), trace);
+    }
+
+    #[test]
+    fn replace() {
+        use crate::Matrix;
+        let matrix = Matrix::new(vec![0.0, 2.0, 1e-12, 0.0], (2, 2)).expect("Impossible");
+        let replaced = matrix.replace(0.0, 1.0, 1e-10);
+        assert_eq!(replaced.items, vec![1.0, 2.0, 1.0, 1.0]);
+        // exact matching leaves the tiny value
+        assert_eq!(
+            matrix.replace(0.0, 1.0, 0.0).items,
+            vec![1.0, 2.0, 1e-12, 1.0]
+        );
+    }
+
+    #[test]
+    fn checked_operations() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)).expect("Impossible");
+        let b = Matrix::generate(|i, j| (i + j) as f64, (2, 3));
+
+        assert!(a.checked_add(&b).expect("Impossible") == a.clone() + b.clone());
+        assert!(a.checked_sub(&b).expect("Impossible") == a.clone() - b.clone());
+        let bt = b.transpose();
+        assert!(a.checked_mul(&bt).expect("Impossible") == a.clone() * bt.clone());
+
+        assert!(matches!(
+            a.checked_add(&bt),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        assert!(matches!(
+            a.checked_sub(&bt),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        assert!(matches!(
+            a.checked_mul(&b),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_mismatched_orders_panics() {
+        use crate::Matrix;
+        let _ = Matrix::null_matrix((2, 3)) + Matrix::null_matrix((3, 2));
+    }
+
+    #[test]
+    fn apply_row_op() {
+        use crate::{Errors, Matrix, RowOp};
+        let original = Matrix::new(vec![2.0, 4.0, -2.0, 1.0, 5.0, 3.0, 0.0, 1.0, 2.0], (3, 3))
+            .expect("Impossible");
+        // reduce to the identity
+        let ops = [
+            RowOp::Scale(1, 0.5),
+            RowOp::AddScaled {
+                target: 2,
+                source: 1,
+                factor: -1.0,
+            },
+            RowOp::Swap(2, 3),
+            RowOp::AddScaled {
+                target: 3,
+                source: 2,
+                factor: -3.0,
+            },
+            RowOp::Scale(3, -0.5),
+            RowOp::AddScaled {
+                target: 2,
+                source: 3,
+                factor: -2.0,
+            },
+            RowOp::AddScaled {
+                target: 1,
+                source: 3,
+                factor: 1.0,
+            },
+            RowOp::AddScaled {
+                target: 1,
+                source: 2,
+                factor: -2.0,
+            },
+        ];
+        let mut matrix = original.clone();
+        for &op in ops.iter() {
+            matrix.apply_row_op(op).expect("Impossible");
+        }
+        assert!(matrix.approx_eq(&Matrix::identity_matrix(3)));
+
+        // replaying the operations on the identity gives the inverse
+        let mut replayed = Matrix::identity_matrix(3);
+        for &op in ops.iter() {
+            replayed.apply_row_op(op).expect("Impossible");
+        }
+        assert!(replayed.approx_eq(&original.inverse().expect("Impossible")));
+
+        assert!(matches!(
+            matrix.apply_row_op(RowOp::Swap(1, 4)),
+            Err(Errors::IndexOutOfRange)
+        ));
+        assert!(matches!(
+            matrix.apply_row_op(RowOp::Scale(0, 2.0)),
+            Err(Errors::IndexOutOfRange)
+        ));
+        // adding a row to itself is a scale, not an elementary addition
+        let before = matrix.clone();
+        assert!(matches!(
+            matrix.apply_row_op(RowOp::AddScaled {
+                target: 2,
+                source: 2,
+                factor: 1.0,
+            }),
+            Err(Errors::IndexOutOfRange)
+        ));
+        assert!(matrix == before);
+    }
+
+    #[test]
+    fn pow() {
+        use crate::{Errors, Matrix};
+        let a = Matrix::new(vec![1.0, 2.0, 0.0, -1.0, 3.0, 1.0, 2.0, 0.0, 1.0], (3, 3))
+            .expect("Impossible");
+        assert!(a.pow(3).expect("Impossible") == a.clone() * a.clone() * a.clone());
+        assert!(a.pow(1).expect("Impossible") == a);
+        assert!(a.pow(0).expect("Impossible") == Matrix::identity_matrix(3));
+        let mut expected = Matrix::identity_matrix(3);
+        for _ in 0..13 {
+            expected *= a.clone();
+        }
+        assert!(a.pow(13).expect("Impossible") == expected);
+
+        // paths of length 4 in a directed cycle of 3 nodes lead one step further
+        let cycle = Matrix::new(vec![0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0], (3, 3))
+            .expect("Impossible");
+        assert!(cycle.pow(4).expect("Impossible") == cycle);
+
+        assert!(matches!(
+            Matrix::null_matrix((2, 3)).pow(2),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+    }
+
+    #[test]
+    fn apply_row_op_tracked() {
+        use crate::{Matrix, RowOp};
+        let original = Matrix::new(
+            vec![
+                0.0, 2.0, 1.0, 3.0, 1.0, -1.0, 2.0, 0.5, 4.0, 1.0, 2.0, 0.0, -2.0, 1.0, 1.0, 3.0,
+            ],
+            (4, 4),
+        )
+        .expect("Impossible");
+        let n = 4;
+        let mut matrix = original.clone();
+        let mut factor = 1.0;
+        // Gaussian elimination with partial pivoting down to the identity
+        for k in 1..=n {
+            let pivot_row = (k..=n)
+                .max_by(|&a, &b| matrix[(a, k)].abs().total_cmp(&matrix[(b, k)].abs()))
+                .expect("Impossible");
+            factor *= matrix.apply_row_op_tracked(RowOp::Swap(k, pivot_row));
+            factor *= matrix.apply_row_op_tracked(RowOp::Scale(k, 1.0 / matrix[(k, k)]));
+            for i in (1..=n).filter(|&i| i != k) {
+                let multiple = -matrix[(i, k)];
+                factor *= matrix.apply_row_op_tracked(RowOp::AddScaled {
+                    target: i,
+                    source: k,
+                    factor: multiple,
+                });
+            }
+        }
+        assert!(matrix.approx_eq(&Matrix::identity_matrix(n)));
+        // det(I) = factor * det(A)
+        let det = original.det().expect("Impossible");
+        assert!((1.0 / factor - det).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_row_op_tracked_missing_row() {
+        use crate::{Matrix, RowOp};
+        let mut matrix = Matrix::identity_matrix(2);
+        matrix.apply_row_op_tracked(RowOp::Scale(3, 2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_row_op_tracked_same_row() {
+        use crate::{Matrix, RowOp};
+        // would scale the determinant by 1 + factor, not by 1
+        let mut matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).expect("Impossible");
+        matrix.apply_row_op_tracked(RowOp::AddScaled {
+            target: 1,
+            source: 1,
+            factor: 2.0,
+        });
     }
 }
@@ -0,0 +1,92 @@
+use crate::{Errors, Matrix};
+use std::cell::OnceCell;
+
+/// # Matrix view
+/// Borrows a matrix and caches its invariants, so asking for them again does not recompute them<br>
+/// The borrow keeps the matrix from changing while the view is alive, which keeps the cache valid
+///
+/// ## Examples
+/// ```
+/// use math_matrix::Matrix;
+/// let matrix = Matrix::new(vec![1.0, 2.0, 3.0, 4.0], (2, 2)).unwrap();
+/// let view = matrix.view();
+///
+/// assert_eq!(view.determinant().unwrap(), -2.0);
+/// assert_eq!(view.rank(), 2);
+/// assert_eq!(view.frobenius_norm(), 30.0_f64.sqrt());
+/// ```
+pub struct MatrixView<'a> {
+    matrix: &'a Matrix,
+    determinant: OnceCell<f64>,
+    rank: OnceCell<u32>,
+    frobenius_norm: OnceCell<f64>,
+}
+
+impl<'a> MatrixView<'a> {
+    pub fn new(matrix: &'a Matrix) -> MatrixView<'a> {
+        MatrixView {
+            matrix,
+            determinant: OnceCell::new(),
+            rank: OnceCell::new(),
+            frobenius_norm: OnceCell::new(),
+        }
+    }
+    /// # Matrix
+    /// The borrowed matrix
+    pub fn matrix(&self) -> &'a Matrix {
+        self.matrix
+    }
+    /// # Determinant
+    /// Same as [`Matrix::det`], computed on the first call<br>
+    /// Returns [`Result`], [`Ok`] if the matrix is square, [`Err`] otherwise
+    pub fn determinant(&self) -> Result<f64, Errors> {
+        if let Some(&det) = self.determinant.get() {
+            return Ok(det);
+        }
+        let det = self.matrix.det()?;
+        Ok(*self.determinant.get_or_init(|| det))
+    }
+    /// # Rank
+    /// Same as [`Matrix::rank`], computed on the first call
+    pub fn rank(&self) -> u32 {
+        *self.rank.get_or_init(|| self.matrix.rank())
+    }
+    /// # Frobenius norm
+    /// Square root of the sum of the squares of the elements, computed on the first call
+    pub fn frobenius_norm(&self) -> f64 {
+        *self.frobenius_norm.get_or_init(|| {
+            self.matrix
+                .frobenius_inner_product(self.matrix)
+                .expect("Impossible")
+                .sqrt()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn determinant_cached() {
+        use crate::{Errors, Matrix};
+        let matrix = Matrix::new(vec![2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0], (3, 3))
+            .expect("Impossible");
+        let view = matrix.view();
+        assert!(view.determinant.get().is_none());
+        let det = view.determinant().expect("Impossible");
+        assert_eq!(det, matrix.det().expect("Impossible"));
+        // the second call reads the cache
+        assert_eq!(view.determinant.get(), Some(&det));
+        assert_eq!(view.determinant().expect("Impossible"), det);
+
+        assert_eq!(view.rank(), 3);
+        assert_eq!(view.rank.get(), Some(&3));
+
+        let rectangular = Matrix::null_matrix((2, 3));
+        let view = rectangular.view();
+        assert!(matches!(
+            view.determinant(),
+            Err(Errors::IncorrectOrdersForOperation)
+        ));
+        assert!(view.determinant.get().is_none());
+    }
+}